@@ -13,6 +13,9 @@ fn load(font: &Font) -> Vec<String> {
 fn criterion_benchmark(c: &mut Criterion) {
     let font = Font::new(FONT).unwrap();
     c.bench_function("render-svg", |b| b.iter(|| load(black_box(&font))));
+    c.bench_function("render-svg-par", |b| {
+        b.iter(|| black_box(&font).svg_previews_par());
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
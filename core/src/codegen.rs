@@ -1,15 +1,20 @@
 //! Code generation utilities for fonts
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::format_ident;
-use std::{collections::HashMap, vec};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    vec,
+};
 
-use crate::font::{Font, StringKind};
+use crate::font::{Font, Glyph, StringKind};
 
 mod docstring;
 use docstring::DocstringExt;
 
 mod to_ident;
-use to_ident::{to_categories, to_identifiers, ToIdentExt};
+pub use to_ident::IdentifierCase;
+use to_ident::ToIdentExt;
+pub(crate) use to_ident::{to_categories, to_identifiers};
 
 mod category;
 use category::FontCategoryDesc;
@@ -21,6 +26,110 @@ pub use glyph::GlyphDesc;
 #[cfg_attr(docsrs, doc(cfg(feature = "codegen")))]
 pub use quote::quote;
 
+/// Options controlling how code is generated for a font
+#[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct CodegenOptions {
+    /// If true, generate one giant enum instead of a set of categories
+    pub skip_categories: bool,
+
+    /// If true, omit the SVG preview images from the generated doc comments, and the generated `svg` method  
+    /// Greatly reduces the size of the generated code for large fonts
+    pub skip_previews: bool,
+
+    /// If true, generate a bitflags-style `Category` type, and a `category` method on the wrapper enum  
+    /// Only applies to fonts split into more than one category, and at most 128 of them
+    pub category_flags: bool,
+
+    /// If true, SVG previews are written to sidecar files instead of being inlined, and referenced with `include_str!`  
+    /// Keeps the generated code small for large fonts - the files must be written to `OUT_DIR`, see [`FontDesc::sidecar_files`]
+    pub sidecar_previews: bool,
+
+    /// If true, glyphs in the Microsoft Symbol private-use range (`U+F000..=U+F0FF`) are moved to `U+0000..=U+00FF`  
+    /// Glyphs are left alone if the font already uses the normalized codepoint - the original value is available from a generated `raw_codepoint` method
+    pub normalize_symbol_codepoints: bool,
+
+    /// If provided, only the glyphs with these codepoints are included in the generated code  
+    /// Useful for trimming a large icon font down to the icons actually used
+    pub codepoints: Option<Vec<u32>>,
+
+    /// If true, `.notdef` and the other non-printing standard glyphs (`.null`, `nonmarkingreturn`) are left out of the generated code  
+    /// They are still available from the [`Font`] itself
+    pub skip_non_printing: bool,
+
+    /// The casing used for the identifiers of glyph variants - `PascalCase` by default  
+    /// Category names are always `PascalCase`
+    pub identifier_case: IdentifierCase,
+
+    /// If true, generate `iced_font` and `into_text` methods for using the glyphs with `iced`, gated behind `#[cfg(feature = "iced")]`  
+    /// The crate including the generated code needs an `iced` feature enabling its `iced` dependency - only applies to fonts with a family name
+    pub iced_helpers: bool,
+}
+
+/// Names of the standard Macintosh glyphs that never draw anything, skipped by [`CodegenOptions::skip_non_printing`]
+const NON_PRINTING_GLYPHS: [&str; 3] = [".notdef", ".null", "nonmarkingreturn"];
+
+/// The start of the private-use range that Microsoft Symbol fonts map their glyphs into
+const SYMBOL_PUA_START: u32 = 0xF000;
+
+/// Returns the glyphs of the font to generate code for  
+/// Applies the codepoint allowlist, if there is one, and drops non-printing glyphs if requested - glyphs are borrowed from the font, not cloned
+///
+/// Glyphs without a codepoint are always dropped, since every variant is keyed by its codepoint
+fn select_glyphs<'a>(font: &'a Font, options: &CodegenOptions) -> Vec<&'a Glyph> {
+    let allowed: Option<HashSet<u32>> = options
+        .codepoints
+        .as_ref()
+        .map(|codepoints| codepoints.iter().copied().collect());
+
+    font.glyphs()
+        .iter()
+        .filter(|glyph| {
+            allowed
+                .as_ref()
+                .is_none_or(|allowed| glyph.codepoint().is_some_and(|c| allowed.contains(&c)))
+        })
+        .filter(|glyph| !options.skip_non_printing || !NON_PRINTING_GLYPHS.contains(&glyph.name()))
+        .filter(|glyph| glyph.codepoint().is_some())
+        .collect()
+}
+
+/// Removes glyphs whose codepoint is already used by an earlier glyph  
+/// Returns the remaining glyphs, and the collisions as `(codepoint, kept, skipped)` glyph names
+fn dedup_codepoints(glyphs: Vec<&Glyph>) -> (Vec<&Glyph>, Vec<(u32, String, String)>) {
+    let mut seen: HashMap<u32, &str> = HashMap::with_capacity(glyphs.len());
+    let mut collisions = vec![];
+    let mut unique = Vec::with_capacity(glyphs.len());
+    for glyph in glyphs {
+        let Some(codepoint) = glyph.codepoint() else {
+            continue;
+        };
+
+        if let Some(kept) = seen.get(&codepoint) {
+            let skipped = glyph.name().to_string();
+            collisions.push((codepoint, (*kept).to_string(), skipped));
+            continue;
+        }
+
+        seen.insert(codepoint, glyph.name());
+        unique.push(glyph);
+    }
+
+    (unique, collisions)
+}
+
+/// Generates doc lines summarizing the constants and methods of a generated enum, as `(item, description)` pairs  
+/// Rustdoc lists every variant before any associated item, so without this they are buried under thousands of glyphs
+fn helper_docs(items: &[(&str, &str)]) -> Vec<String> {
+    let mut docs = vec![String::new(), "# Constants and methods".to_string()];
+    docs.extend(
+        items
+            .iter()
+            .map(|(item, description)| format!("- [`Self::{item}`] - {description}")),
+    );
+    docs
+}
+
 /// Describes a font used for code generation
 #[derive(Debug, Clone)]
 pub struct FontDesc {
@@ -28,31 +137,68 @@ pub struct FontDesc {
     family: Option<String>,
     comments: Vec<String>,
     categories: Vec<FontCategoryDesc>,
+    category_flags: bool,
+    iced_helpers: bool,
+    collisions: Vec<(u32, String, String)>,
 }
 impl FontDesc {
     /// Describe the font from a `Font` instance, optionally skipping categories
+    #[must_use]
     pub fn from_font(identifier: &str, font: &Font, skip_categories: bool) -> Self {
+        let options = CodegenOptions {
+            skip_categories,
+            ..Default::default()
+        };
+        Self::with_options(identifier, font, &options)
+    }
+
+    /// Describe the font from a `Font` instance, using the specified codegen options
+    #[must_use]
+    pub fn with_options(identifier: &str, font: &Font, options: &CodegenOptions) -> Self {
         let identifier = identifier.to_string();
         let family = font.string(StringKind::FontFamily).map(ToString::to_string);
         let mut comments = font.gen_docblock();
 
+        let glyphs = select_glyphs(font, options);
+
+        //
+        // Two variants with the same discriminant would not compile - keep the first glyph for each codepoint
+        let (glyphs, collisions) = dedup_codepoints(glyphs);
+
         //
         // Get initial categories
-        let mut categories = if skip_categories {
+        let mut categories = if options.skip_categories {
             // If set, skip categorization all-together
-            let glyphs = to_identifiers(font.glyphs());
-            vec![FontCategoryDesc::new(&identifier, glyphs)]
+            let glyphs = to_identifiers(glyphs, options.identifier_case);
+            vec![FontCategoryDesc::new(&identifier, glyphs, options)]
         } else {
             // Otherwise, attempt a best-effort categorization
-            let raw_categories = to_categories(font.glyphs());
+            let raw_categories = to_categories(glyphs, options.identifier_case);
             let mut categories = Vec::with_capacity(raw_categories.len());
             for (name, glyphs) in raw_categories {
-                categories.push(FontCategoryDesc::new(&name, glyphs));
+                categories.push(FontCategoryDesc::new(&name, glyphs, options));
             }
 
             categories
         };
 
+        //
+        // Move Microsoft Symbol codepoints out of the private-use area, unless they would collide
+        if options.normalize_symbol_codepoints {
+            let glyphs = categories.iter_mut().flat_map(FontCategoryDesc::glyphs_mut);
+            for glyph in glyphs {
+                let codepoint = glyph.codepoint();
+                if !(SYMBOL_PUA_START..=SYMBOL_PUA_START + 0xFF).contains(&codepoint) {
+                    continue;
+                }
+
+                let normalized = codepoint - SYMBOL_PUA_START;
+                if font.glyph(normalized).is_none() {
+                    glyph.set_codepoint(normalized);
+                }
+            }
+        }
+
         //
         // If we have just one, fall-back to single-cat generation
         if categories.len() == 1 {
@@ -60,12 +206,18 @@ impl FontDesc {
             category.set_name(identifier.clone());
             category.set_comments(comments.drain(..));
             category.sort();
+            if options.sidecar_previews {
+                category.set_sidecar_dir(&format!("font_previews_{identifier}"));
+            }
 
             return Self {
                 identifier,
                 family,
                 comments,
                 categories,
+                category_flags: options.category_flags,
+                iced_helpers: options.iced_helpers,
+                collisions,
             };
         }
 
@@ -75,7 +227,7 @@ impl FontDesc {
             .iter()
             .position(|c| c.name() == "Other")
             .map_or_else(
-                || FontCategoryDesc::new("Other", HashMap::default()),
+                || FontCategoryDesc::new("Other", BTreeMap::default(), options),
                 |idx| categories.swap_remove(idx),
             );
 
@@ -90,7 +242,8 @@ impl FontDesc {
 
                 let (name, glyphs) = category.into_inner();
                 for mut glyph in glyphs {
-                    let identifier = name.merge_identifiers(glyph.identifier());
+                    let identifier =
+                        name.merge_identifiers(glyph.identifier(), options.identifier_case);
                     glyph.set_identifier(identifier);
                     other.insert(glyph);
                 }
@@ -107,15 +260,55 @@ impl FontDesc {
         // Sort the categories by name
         categories.sort_by(|a, b| a.name().cmp(b.name()));
         categories.iter_mut().for_each(FontCategoryDesc::sort);
+        if options.sidecar_previews {
+            let dir = format!("font_previews_{identifier}");
+            for category in &mut categories {
+                category.set_sidecar_dir(&dir);
+            }
+        }
 
         Self {
             identifier,
             family,
             comments,
             categories,
+            category_flags: options.category_flags,
+            iced_helpers: options.iced_helpers,
+            collisions,
         }
     }
 
+    /// Inject additional code into the impl of a single category's enum, such as a category-specific constant  
+    /// Categories are named as in the generated `categories` module - a font with a single category uses the font's own identifier
+    ///
+    /// Does nothing if there is no category with that name
+    #[must_use]
+    pub fn with_category_impl(mut self, category: &str, tokens: TokenStream) -> Self {
+        if let Some(category) = self.categories.iter_mut().find(|c| c.name() == category) {
+            category.inject_impl(tokens);
+        }
+
+        self
+    }
+
+    /// Returns the glyphs left out of the generated code because another glyph already used their codepoint  
+    /// Each entry is `(codepoint, kept, skipped)`, naming the glyphs by their postscript names
+    #[must_use]
+    pub fn collisions(&self) -> &[(u32, String, String)] {
+        &self.collisions
+    }
+
+    /// Returns the sidecar files for the SVG previews, as paths relative to `OUT_DIR` and their contents  
+    /// Empty unless [`CodegenOptions::sidecar_previews`] is set
+    #[must_use]
+    pub fn sidecar_files(&self) -> Vec<(String, String)> {
+        self.categories
+            .iter()
+            .flat_map(FontCategoryDesc::glyphs)
+            .flat_map(GlyphDesc::sidecar_files)
+            .collect()
+    }
+
     /// Returns true if this font has only one category
     #[must_use]
     pub fn is_single_category(&self) -> bool {
@@ -133,20 +326,37 @@ impl FontDesc {
         let outer_comments = &self.comments;
         let font_family = self.family.iter();
         let injection = extra_impl.iter();
+        let iced_helpers = self.codegen_iced_helpers();
+        let iced_helpers = iced_helpers.iter();
 
         if self.is_single_category() {
             let category = &self.categories[0];
+            let helpers = self
+                .family
+                .as_ref()
+                .map(|_| ("FONT_FAMILY", "The family name for the font"));
 
-            category.codegen(Some(quote! {
-                #(
-                    /// The family name for font
-                    pub const FONT_FAMILY: &str = #font_family;
-                )*
+            let code = category.codegen_with_helpers(
+                helpers,
+                Some(quote! {
+                    #(
+                        /// The family name for font
+                        pub const FONT_FAMILY: &str = #font_family;
+                    )*
+
+                    #(
+                        #injection
+                    )*
+                }),
+            );
+
+            quote! {
+                #code
 
                 #(
-                    #injection
+                    #iced_helpers
                 )*
-            }))
+            }
         } else {
             //
             // Categories in a module, generate an outer wrapper enum
@@ -155,6 +365,109 @@ impl FontDesc {
                 categories.push(category.codegen(None));
             }
 
+            let category_flags = self.codegen_category_flags();
+            let category_method = category_flags.as_ref().map(|_| {
+                let variant_names = self
+                    .categories
+                    .iter()
+                    .map(|category| format_ident!("{}", category.name()));
+                quote! {
+                    /// Returns the category of the glyph, as a flag
+                    #[must_use]
+                    pub fn category(&self) -> Category {
+                        match self {
+                            #( Self :: #variant_names(_) => Category :: #variant_names, )*
+                        }
+                    }
+                }
+            });
+            let has_category_method = category_method.is_some();
+            let category_flags = category_flags.iter();
+            let category_method = category_method.iter();
+
+            let svg_method = self
+                .categories
+                .iter()
+                .all(FontCategoryDesc::has_svg)
+                .then(|| {
+                    let variant_names = self
+                        .categories
+                        .iter()
+                        .map(|category| format_ident!("{}", category.name()));
+                    quote! {
+                        /// Returns the SVG preview of the glyph
+                        #[must_use]
+                        pub fn svg(&self) -> &'static str {
+                            match self {
+                                #( Self :: #variant_names(inner) => inner.svg(), )*
+                            }
+                        }
+                    }
+                });
+            let has_svg_method = svg_method.is_some();
+            let svg_method = svg_method.iter();
+
+            let raw_codepoint_method = self
+                .categories
+                .iter()
+                .all(FontCategoryDesc::has_raw_codepoints)
+                .then(|| {
+                    let variant_names = self
+                        .categories
+                        .iter()
+                        .map(|category| format_ident!("{}", category.name()));
+                    quote! {
+                        /// Returns the codepoint of the glyph as it appears in the font, before symbol codepoints were normalized
+                        #[must_use]
+                        pub fn raw_codepoint(&self) -> u32 {
+                            match self {
+                                #( Self :: #variant_names(inner) => inner.raw_codepoint(), )*
+                            }
+                        }
+                    }
+                });
+            let has_raw_codepoint_method = raw_codepoint_method.is_some();
+            let raw_codepoint_method = raw_codepoint_method.iter();
+
+            //
+            // Summarize the associated items at the top of the enum's docs
+            let mut helpers = vec![];
+            if self.family.is_some() {
+                helpers.push(("FONT_FAMILY", "The family name for the font"));
+            }
+            helpers.extend([
+                ("TOTAL_GLYPHS", "The number of glyphs in the font"),
+                ("ALL", "Every glyph in the font"),
+                (
+                    "entries",
+                    "The name and codepoint of every glyph in the font",
+                ),
+                ("name", "The postscript name of a glyph"),
+                ("advance_width", "The advance width of a glyph"),
+                ("left_side_bearing", "The left side bearing of a glyph"),
+                ("unicode_range", "The unicode block of a glyph"),
+            ]);
+            if has_category_method {
+                helpers.push(("category", "The category of a glyph, as a flag"));
+            }
+            if has_svg_method {
+                helpers.push(("svg", "The SVG preview of a glyph"));
+            }
+            if has_raw_codepoint_method {
+                helpers.push((
+                    "raw_codepoint",
+                    "The codepoint of a glyph before normalization",
+                ));
+            }
+            let helper_docs = helper_docs(&helpers);
+
+            let n_glyphs = self
+                .categories
+                .iter()
+                .map(|c| c.glyphs().len())
+                .sum::<usize>();
+            let mut all_variants = Vec::with_capacity(n_glyphs);
+            let mut all_entries = Vec::with_capacity(n_glyphs);
             let mut variant_names = Vec::with_capacity(categories.len());
             let mut variants = Vec::with_capacity(categories.len());
             for category in &self.categories {
@@ -165,6 +478,14 @@ impl FontDesc {
                     #name(categories :: #name),
                 };
 
+                for glyph in category.glyphs() {
+                    let (glyph_name, codepoint) = (glyph.name(), glyph.codepoint());
+                    all_entries.push(quote! { (#glyph_name, #codepoint) });
+
+                    let glyph = format_ident!("{}", glyph.identifier());
+                    all_variants.push(quote! { Self :: #name(categories :: #name :: #glyph) });
+                }
+
                 variant_names.push(name);
                 variants.push(variant);
             }
@@ -176,9 +497,11 @@ impl FontDesc {
                 }
 
                 #[allow(rustdoc::bare_urls)]
+                #[allow(clippy::doc_markdown)]
                 #( #[doc = #outer_comments] )*
                 #[doc = ""]
                 #[doc = "See the [`categories`] module for more information."]
+                #( #[doc = #helper_docs] )*
                 #[derive(Debug, Clone, Copy)]
                 #[rustfmt::skip]
                 pub enum #identifier {
@@ -193,6 +516,27 @@ impl FontDesc {
                         pub const FONT_FAMILY: &str = #font_family;
                     )*
 
+                    /// The total number of glyphs in this font, across all categories
+                    pub const TOTAL_GLYPHS: usize = #n_glyphs;
+
+                    /// All the glyphs in this font, across all categories
+                    #[allow(clippy::large_stack_arrays)]
+                    pub const ALL: [Self; #n_glyphs] = [
+                        #( #all_variants, )*
+                    ];
+
+                    /// Returns the postscript name and codepoint of every glyph in this font, in the same order as [`Self::ALL`]
+                    /// Useful for building a search index without loading the font
+                    #[allow(clippy::unreadable_literal)]
+                    #[allow(clippy::large_stack_arrays)]
+                    #[must_use]
+                    pub fn entries() -> &'static [(&'static str, u32)] {
+                        const ENTRIES: [(&str, u32); #n_glyphs] = [
+                            #( #all_entries, )*
+                        ];
+                        &ENTRIES
+                    }
+
                     /// Returns the postscript name of the glyph
                     #[allow(clippy::too_many_lines)]
                     #[allow(clippy::match_same_arms)]
@@ -203,11 +547,55 @@ impl FontDesc {
                         }
                     }
 
+                    /// Returns the horizontal advance width of the glyph, in font units
+                    #[must_use]
+                    pub fn advance_width(&self) -> u16 {
+                        match self {
+                            #( Self :: #variant_names(inner) => inner.advance_width(), )*
+                        }
+                    }
+
+                    /// Returns the left side bearing of the glyph, in font units
+                    #[must_use]
+                    pub fn left_side_bearing(&self) -> i16 {
+                        match self {
+                            #( Self :: #variant_names(inner) => inner.left_side_bearing(), )*
+                        }
+                    }
+
+                    /// Returns the name of the unicode block containing the glyph, such as `Private Use Area`
+                    #[must_use]
+                    pub fn unicode_range(&self) -> &'static str {
+                        match self {
+                            #( Self :: #variant_names(inner) => inner.unicode_range(), )*
+                        }
+                    }
+
+                    #(
+                        #category_method
+                    )*
+
+                    #(
+                        #svg_method
+                    )*
+
+                    #(
+                        #raw_codepoint_method
+                    )*
+
                     #(
                         #injection
                     )*
                 }
 
+                #(
+                    #category_flags
+                )*
+
+                #(
+                    #iced_helpers
+                )*
+
                 #(
                     impl From<categories :: #variant_names> for #identifier {
                         fn from(value: categories :: #variant_names) -> Self {
@@ -230,6 +618,20 @@ impl FontDesc {
                     }
                 }
 
+                impl TryFrom<char> for #identifier {
+                    type Error = char;
+
+                    /// Returns the glyph for the character from the first category containing it, or the character itself if there is none
+                    fn try_from(value: char) -> Result<Self, char> {
+                        #(
+                            if let Ok(inner) = categories :: #variant_names::try_from(value) {
+                                return Ok(Self :: #variant_names(inner));
+                            }
+                        )*
+                        Err(value)
+                    }
+                }
+
                 impl From<#identifier> for u32 {
                     fn from(value: #identifier) -> Self {
                         match value {
@@ -244,6 +646,12 @@ impl FontDesc {
                     }
                 }
 
+                impl From<#identifier> for String {
+                    fn from(value: #identifier) -> Self {
+                        char::from(value).to_string()
+                    }
+                }
+
                 impl std::fmt::Display for #identifier {
                     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                         match self {
@@ -254,6 +662,134 @@ impl FontDesc {
             }
         }
     }
+
+    /// Generate the `iced` helper methods, gated behind the `iced` feature of the including crate  
+    /// Returns `None` if disabled, or if the font has no family name for `iced` to select it by
+    fn codegen_iced_helpers(&self) -> Option<TokenStream> {
+        if !self.iced_helpers || self.family.is_none() {
+            return None;
+        }
+
+        let identifier = format_ident!("{}", &self.identifier);
+        Some(quote! {
+            #[cfg(feature = "iced")]
+            #[allow(dead_code)]
+            impl #identifier {
+                /// Returns a font definition for this font
+                /// Used for the `font` method on iced text widgets
+                #[must_use]
+                pub fn iced_font() -> iced::Font {
+                    iced::font::Font {
+                        family: iced::font::Family::Name(Self::FONT_FAMILY),
+                        ..Default::default()
+                    }
+                }
+
+                /// Converts this glyph into an iced Text widget
+                /// Sets the font-size of the new widget
+                #[must_use]
+                pub fn into_text<'a, Theme>(
+                    self,
+                    font_size: impl Into<iced::Pixels>,
+                ) -> iced::widget::Text<'a, Theme>
+                where
+                    Theme: iced::widget::text::Catalog,
+                {
+                    iced::widget::Text::new(char::from(self))
+                        .font(Self::iced_font())
+                        .size(font_size)
+                }
+            }
+        })
+    }
+
+    /// Generate the bitflags-style `Category` type, with one flag per category  
+    /// Returns `None` if disabled, or if there are too many categories to fit in a `u128`
+    fn codegen_category_flags(&self) -> Option<TokenStream> {
+        if !self.category_flags || self.categories.len() > 128 {
+            return None;
+        }
+
+        let width = self.categories.len().next_power_of_two().max(8);
+        let bits = format_ident!("u{width}");
+        let unused = Literal::usize_unsuffixed(width - self.categories.len());
+
+        let mut flags = Vec::with_capacity(self.categories.len());
+        for (i, category) in self.categories.iter().enumerate() {
+            let name = format_ident!("{}", category.name());
+            let doc = format!("The glyphs in [`categories::{name}`]");
+            let shift = Literal::usize_unsuffixed(i);
+            flags.push(quote! {
+                #[doc = #doc]
+                pub const #name: Self = Self(1 << #shift);
+            });
+        }
+
+        Some(quote! {
+            /// A set of categories from this font, stored as bitflags
+            ///
+            /// Flags can be combined with `|`, and tested with [`Category::contains`] or [`Category::intersects`]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            pub struct Category(#bits);
+
+            #[rustfmt::skip]
+            #[allow(dead_code)]
+            #[allow(non_upper_case_globals)]
+            impl Category {
+                /// No categories
+                pub const EMPTY: Self = Self(0);
+
+                /// Every category in the font
+                pub const ALL: Self = Self(#bits::MAX >> #unused);
+
+                #( #flags )*
+
+                /// Returns the raw bits of the set
+                #[must_use]
+                pub const fn bits(self) -> #bits {
+                    self.0
+                }
+
+                /// Returns true if no categories are set
+                #[must_use]
+                pub const fn is_empty(self) -> bool {
+                    self.0 == 0
+                }
+
+                /// Returns true if every category in `other` is also in this set
+                #[must_use]
+                pub const fn contains(self, other: Self) -> bool {
+                    self.0 & other.0 == other.0
+                }
+
+                /// Returns true if any category in `other` is also in this set
+                #[must_use]
+                pub const fn intersects(self, other: Self) -> bool {
+                    self.0 & other.0 != 0
+                }
+            }
+
+            impl std::ops::BitOr for Category {
+                type Output = Self;
+                fn bitor(self, rhs: Self) -> Self {
+                    Self(self.0 | rhs.0)
+                }
+            }
+
+            impl std::ops::BitOrAssign for Category {
+                fn bitor_assign(&mut self, rhs: Self) {
+                    self.0 |= rhs.0;
+                }
+            }
+
+            impl std::ops::BitAnd for Category {
+                type Output = Self;
+                fn bitand(self, rhs: Self) -> Self {
+                    Self(self.0 & rhs.0)
+                }
+            }
+        })
+    }
 }
 
 impl From<&FontDesc> for TokenStream {
@@ -261,3 +797,256 @@ impl From<&FontDesc> for TokenStream {
         value.codegen(None)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::font::GlyphPreview;
+
+    const FONT: &[u8] = include_bytes!("../../examples/slick.ttf");
+
+    /// Loads the test font with prefixed glyph names, so the glyphs are split into `arrows` and `shapes` categories
+    fn categorized_font() -> Font {
+        let mut ttf = crate::raw::ttf::TrueTypeFont::new(FONT).unwrap();
+        for (i, name) in ttf.post_table.glyph_names.iter_mut().enumerate() {
+            *name = format!("{}-{name}", ["arrows", "shapes"][i % 2]);
+        }
+        Font::from(ttf)
+    }
+
+    #[test]
+    fn test_codepoint_allowlist() {
+        let font = Font::new(FONT).unwrap();
+        let options = CodegenOptions {
+            codepoints: Some(vec![0x2022, 0x2190]),
+            skip_previews: true,
+            ..Default::default()
+        };
+
+        let desc = FontDesc::with_options("Slick", &font, &options);
+        let code = desc.codegen(None).to_string();
+        assert!(code.contains("Bullet"));
+        assert!(code.contains("Arrowleft"));
+        assert!(!code.contains("Arrowright"));
+        assert!(code.contains("TOTAL_GLYPHS : usize = 2usize"));
+    }
+
+    #[test]
+    fn test_skip_non_printing() {
+        //
+        // Give the glyph mapped to U+0000 the `.notdef` name, as some fonts do
+        let mut ttf = crate::raw::ttf::TrueTypeFont::new(FONT).unwrap();
+        ttf.post_table.glyph_names[1] = ".notdef".to_string();
+        let font = Font::from(ttf);
+        let notdef = font.glyph_by_id(1).unwrap();
+        assert_eq!(notdef.name(), ".notdef");
+
+        let mut options = CodegenOptions {
+            skip_categories: true,
+            skip_previews: true,
+            ..Default::default()
+        };
+        let code = FontDesc::with_options("Slick", &font, &options)
+            .codegen(None)
+            .to_string();
+        assert!(code.contains("Notdef"));
+        assert!(code.contains("Nonmarkingreturn"));
+
+        options.skip_non_printing = true;
+        let code = FontDesc::with_options("Slick", &font, &options)
+            .codegen(None)
+            .to_string();
+        assert!(!code.contains("Notdef"));
+        assert!(!code.contains("Nonmarkingreturn"));
+        assert!(code.contains("Bullet"));
+        assert!(font.glyph_named(".notdef").is_some());
+    }
+
+    #[test]
+    fn test_skip_unmapped() {
+        //
+        // Glyph 0 has no codepoint, so it can never be a variant
+        let font = Font::new(FONT).unwrap();
+        let notdef = font.glyph_by_id(0).unwrap();
+        assert_eq!(notdef.name(), ".notdef");
+        assert_eq!(notdef.codepoint(), None);
+
+        let options = CodegenOptions {
+            skip_categories: true,
+            skip_previews: true,
+            ..Default::default()
+        };
+        let code = FontDesc::with_options("Slick", &font, &options)
+            .codegen(None)
+            .to_string();
+        assert!(!code.contains("Notdef"));
+        assert!(code.contains("Null"));
+    }
+
+    #[test]
+    fn test_entries() {
+        let font = Font::new(FONT).unwrap();
+        let options = CodegenOptions {
+            skip_categories: true,
+            skip_previews: true,
+            ..Default::default()
+        };
+
+        let code = FontDesc::with_options("Slick", &font, &options)
+            .codegen(None)
+            .to_string();
+        assert!(code.contains("pub fn entries () -> & 'static [(& 'static str , u32)]"));
+        assert!(code.contains("(\"bullet\" , 8226u32)"));
+    }
+
+    #[test]
+    fn test_unicode_range_method() {
+        let font = Font::new(FONT).unwrap();
+        let options = CodegenOptions {
+            skip_categories: true,
+            skip_previews: true,
+            ..Default::default()
+        };
+
+        let code = FontDesc::with_options("Slick", &font, &options)
+            .codegen(None)
+            .to_string();
+        assert!(code.contains("pub fn unicode_range (& self) -> & 'static str"));
+        assert!(code.contains("97u32 => \"Basic Latin\""));
+        assert!(code.contains("8592u32 | 8594u32 => \"Arrows\""));
+        assert_eq!(code.matches("=> \"Arrows\"").count(), 1);
+    }
+
+    #[test]
+    fn test_iced_helpers() {
+        let font = Font::new(FONT).unwrap();
+        let mut options = CodegenOptions {
+            skip_previews: true,
+            ..Default::default()
+        };
+
+        let code = FontDesc::with_options("Slick", &font, &options)
+            .codegen(None)
+            .to_string();
+        assert!(!code.contains("iced"));
+
+        options.iced_helpers = true;
+        let code = FontDesc::with_options("Slick", &font, &options)
+            .codegen(None)
+            .to_string();
+        assert!(code.contains("# [cfg (feature = \"iced\")]"));
+        assert!(code.contains("pub fn iced_font () -> iced :: Font"));
+        assert!(code.contains("pub fn into_text"));
+    }
+
+    #[test]
+    fn test_identifier_case() {
+        let font = Font::new(FONT).unwrap();
+        let options = CodegenOptions {
+            skip_categories: true,
+            skip_previews: true,
+            identifier_case: IdentifierCase::ScreamingSnake,
+            ..Default::default()
+        };
+
+        let code = FontDesc::with_options("Slick", &font, &options)
+            .codegen(None)
+            .to_string();
+        assert!(code.contains("pub enum Slick"));
+        assert!(code.contains("Self :: BULLET"));
+        assert!(code.contains("Self :: ARROWLEFT"));
+        assert!(!code.contains("Bullet"));
+    }
+
+    #[test]
+    fn test_dedup_codepoints() {
+        let preview = GlyphPreview::Svg("".into());
+        let glyphs = [
+            Glyph::new(0xE000, "first", preview.clone()),
+            Glyph::new(0xE001, "second", preview.clone()),
+            Glyph::new(0xE000, "duplicate", preview),
+        ];
+
+        let (unique, collisions) = dedup_codepoints(glyphs.iter().collect());
+        let names: Vec<_> = unique.iter().map(|glyph| glyph.name()).collect();
+        assert_eq!(names, ["first", "second"]);
+        assert_eq!(
+            collisions,
+            [(0xE000, "first".to_string(), "duplicate".to_string())]
+        );
+
+        let font = Font::new(FONT).unwrap();
+        assert!(FontDesc::from_font("Slick", &font, false)
+            .collisions()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_helper_docs() {
+        let font = Font::new(FONT).unwrap();
+        let options = CodegenOptions {
+            skip_categories: true,
+            skip_previews: true,
+            ..Default::default()
+        };
+
+        let code = FontDesc::with_options("Slick", &font, &options)
+            .codegen(None)
+            .to_string();
+        assert!(code.contains("# Constants and methods"));
+        assert!(code.contains("- [`Self::FONT_FAMILY`]"));
+        assert!(code.contains("- [`Self::TOTAL_GLYPHS`]"));
+        assert!(!code.contains("- [`Self::svg`]"));
+
+        // The summary comes before the variants
+        let summary = code.find("# Constants and methods").unwrap();
+        assert!(summary < code.find("Bullet").unwrap());
+    }
+
+    #[test]
+    fn test_try_from_char() {
+        let font = Font::new(FONT).unwrap();
+        let options = CodegenOptions {
+            skip_categories: true,
+            skip_previews: true,
+            ..Default::default()
+        };
+        let code = FontDesc::with_options("Slick", &font, &options)
+            .codegen(None)
+            .to_string();
+        assert!(code.contains("impl TryFrom < char > for Slick"));
+        assert!(code.contains("8226u32 => Ok (Self :: Bullet)"));
+
+        //
+        // Glyphs split into categories
+        let font = categorized_font();
+        let desc = FontDesc::with_options("Slick", &font, &CodegenOptions::default());
+        assert!(!desc.is_single_category());
+
+        let code = desc.codegen(None).to_string();
+        assert!(code.contains("impl TryFrom < char > for Slick"));
+        assert!(code.contains("categories :: Arrows :: try_from (value)"));
+        assert!(code.contains("return Ok (Self :: Shapes (inner))"));
+    }
+
+    #[test]
+    fn test_category_impl() {
+        let font = categorized_font();
+
+        let code = FontDesc::with_options("Slick", &font, &CodegenOptions::default())
+            .with_category_impl("Arrows", quote! { pub const POINTY: bool = true; })
+            .with_category_impl("Missing", quote! { pub const MISSING: bool = true; })
+            .codegen(Some(quote! { pub const OUTER: bool = true; }))
+            .to_string();
+        assert_eq!(code.matches("pub const POINTY").count(), 1);
+        assert!(!code.contains("MISSING"));
+
+        //
+        // The tokens land in the category's impl, not the wrapper's
+        let arrows = code.find("pub enum Arrows").unwrap();
+        let shapes = code.find("pub enum Shapes").unwrap();
+        let pointy = code.find("pub const POINTY").unwrap();
+        assert!(arrows < pointy && pointy < shapes);
+        assert!(code.find("pub const OUTER").unwrap() > shapes);
+    }
+}
@@ -1,8 +1,10 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-use super::GlyphDesc;
+use crate::unicode_range::unicode_range;
+
+use super::{helper_docs, CodegenOptions, GlyphDesc};
 use crate::font::Glyph;
 
 /// Describes a single category of glyphs in a font
@@ -11,20 +13,28 @@ pub struct FontCategoryDesc {
     identifier: String,
     comments: Vec<String>,
     glyphs: Vec<GlyphDesc>,
+    raw_codepoints: bool,
+    extra_impl: Vec<TokenStream>,
 }
 impl FontCategoryDesc {
     /// Create a new category from a name and a list of glyphs
-    pub fn new(identifier: &str, glyphs: HashMap<String, Glyph>) -> Self {
+    pub fn new(
+        identifier: &str,
+        glyphs: BTreeMap<String, &Glyph>,
+        options: &CodegenOptions,
+    ) -> Self {
         let identifier = identifier.to_string();
         let mut glyphs_: Vec<GlyphDesc> = Vec::with_capacity(glyphs.len());
         for (name, glyph) in glyphs {
-            glyphs_.push(GlyphDesc::new(&name, &glyph));
+            glyphs_.push(GlyphDesc::with_options(name, glyph, options));
         }
 
         let mut inst = Self {
             identifier,
             comments: Vec::with_capacity(1),
             glyphs: glyphs_,
+            raw_codepoints: options.normalize_symbol_codepoints,
+            extra_impl: vec![],
         };
 
         inst.update_comments();
@@ -66,6 +76,23 @@ impl FontCategoryDesc {
         &mut self.glyphs
     }
 
+    /// Move the SVG previews of every glyph into sidecar files - see [`GlyphDesc::set_sidecar_dir`]
+    pub fn set_sidecar_dir(&mut self, dir: &str) {
+        for glyph in &mut self.glyphs {
+            glyph.set_sidecar_dir(dir);
+        }
+    }
+
+    /// Returns true if a `raw_codepoint` method should be generated for this category
+    pub fn has_raw_codepoints(&self) -> bool {
+        self.raw_codepoints
+    }
+
+    /// Returns true if every glyph in this category has an SVG preview
+    pub fn has_svg(&self) -> bool {
+        !self.glyphs.is_empty() && self.glyphs.iter().all(|glyph| glyph.svg().is_some())
+    }
+
     /// Get the name of the category
     pub fn name(&self) -> &str {
         &self.identifier
@@ -85,6 +112,11 @@ impl FontCategoryDesc {
         self.comments = comments.into_iter().collect();
     }
 
+    /// Inject additional code into the generated category's impl, alongside any passed to [`FontCategoryDesc::codegen`]
+    pub fn inject_impl(&mut self, tokens: TokenStream) {
+        self.extra_impl.push(tokens);
+    }
+
     /// Deconstructs the category into its inner glyphs
     pub fn into_inner(self) -> (String, Vec<GlyphDesc>) {
         (self.identifier, self.glyphs)
@@ -93,22 +125,126 @@ impl FontCategoryDesc {
     /// Generates the code for this category
     ///
     /// Optionally, you can inject additional code into the generated category's impl
+    pub fn codegen(&self, extra_impl: Option<TokenStream>) -> TokenStream {
+        self.codegen_with_helpers(None, extra_impl)
+    }
+
+    /// Generates the code for this category, listing an additional item from `extra_impl` in the enum's docs  
+    /// The item is given as `(name, description)`
     #[allow(unused_mut)]
     #[allow(clippy::needless_pass_by_value)]
-    pub fn codegen(&self, extra_impl: Option<TokenStream>) -> TokenStream {
+    #[allow(clippy::too_many_lines)]
+    pub fn codegen_with_helpers(
+        &self,
+        extra_helper: Option<(&str, &str)>,
+        extra_impl: Option<TokenStream>,
+    ) -> TokenStream {
         let identifier = format_ident!("{}", &self.identifier);
         let comments = &self.comments;
-        let injection = extra_impl.iter();
+        let injection = extra_impl.iter().chain(&self.extra_impl);
         let n_glyphs = self.glyphs.len();
 
-        let codepoints = self.glyphs.iter().map(GlyphDesc::codepoint);
+        let codepoints: Vec<_> = self.glyphs.iter().map(GlyphDesc::codepoint).collect();
         let names = self.glyphs.iter().map(GlyphDesc::name);
+        let advance_widths = self.glyphs.iter().map(GlyphDesc::advance_width);
+        let bearings = self.glyphs.iter().map(GlyphDesc::left_side_bearing);
         let variants = self.glyphs.iter().map(GlyphDesc::codegen);
 
+        //
+        // Most glyphs share a block with their neighbours, so group the arms by block
+        let mut blocks: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+        for codepoint in &codepoints {
+            blocks
+                .entry(unicode_range(*codepoint))
+                .or_default()
+                .push(*codepoint);
+        }
+        let (block_names, block_codepoints): (Vec<_>, Vec<_>) = blocks.into_iter().unzip();
+
+        let svg_method = self.has_svg().then(|| {
+            let codepoints = self.glyphs.iter().map(GlyphDesc::codepoint);
+            let svgs = self.glyphs.iter().filter_map(GlyphDesc::svg_tokens);
+            quote! {
+                /// Returns the SVG preview of the glyph
+                #[allow(clippy::too_many_lines)]
+                #[allow(clippy::match_same_arms)]
+                #[allow(clippy::unreadable_literal)]
+                #[must_use]
+                pub fn svg(&self) -> &'static str {
+                    match *self as u32 {
+                        #( #codepoints => #svgs, )*
+                        _ => "",
+                    }
+                }
+            }
+        });
+        let svg_method = svg_method.iter();
+
+        let raw_codepoint_method = self.raw_codepoints.then(|| {
+            let (codepoints, raw_codepoints): (Vec<_>, Vec<_>) = self
+                .glyphs
+                .iter()
+                .filter(|glyph| glyph.codepoint() != glyph.raw_codepoint())
+                .map(|glyph| (glyph.codepoint(), glyph.raw_codepoint()))
+                .unzip();
+            quote! {
+                /// Returns the codepoint of the glyph as it appears in the font, before symbol codepoints were normalized
+                #[allow(clippy::unreadable_literal)]
+                #[must_use]
+                pub fn raw_codepoint(&self) -> u32 {
+                    match *self as u32 {
+                        #( #codepoints => #raw_codepoints, )*
+                        codepoint => codepoint,
+                    }
+                }
+            }
+        });
+        let raw_codepoint_method = raw_codepoint_method.iter();
+
+        //
+        // Summarize the associated items at the top of the enum's docs
+        let mut helpers: Vec<_> = extra_helper.into_iter().collect();
+        helpers.extend([
+            ("TOTAL_GLYPHS", "The number of glyphs in this enum"),
+            ("ALL", "Every glyph in this enum"),
+            ("CODEPOINTS", "The codepoint of every glyph in this enum"),
+            (
+                "entries",
+                "The name and codepoint of every glyph in this enum",
+            ),
+            ("name", "The postscript name of a glyph"),
+            ("advance_width", "The advance width of a glyph"),
+            ("left_side_bearing", "The left side bearing of a glyph"),
+            ("unicode_range", "The unicode block of a glyph"),
+        ]);
+        if self.has_svg() {
+            helpers.push(("svg", "The SVG preview of a glyph"));
+        }
+        if self.raw_codepoints {
+            helpers.push((
+                "raw_codepoint",
+                "The codepoint of a glyph before normalization",
+            ));
+        }
+        let helper_docs = helper_docs(&helpers);
+
+        let all_codepoints = self.glyphs.iter().map(GlyphDesc::codepoint);
+        let entry_names = self.glyphs.iter().map(GlyphDesc::name);
+        let entry_codepoints = self.glyphs.iter().map(GlyphDesc::codepoint);
+        let all_variants: Vec<_> = self
+            .glyphs
+            .iter()
+            .map(|glyph| format_ident!("{}", glyph.identifier()))
+            .collect();
+        let variant_idents = &all_variants;
+
         quote! {
             #[allow(clippy::unreadable_literal)]
             #[allow(rustdoc::bare_urls)]
+            #[allow(clippy::doc_markdown)]
+            #[allow(non_camel_case_types)]
             #( #[doc = #comments] )*
+            #( #[doc = #helper_docs] )*
             #[derive(Debug, Clone, Copy)]
             #[repr(u32)]
             pub enum #identifier {
@@ -120,6 +256,31 @@ impl FontCategoryDesc {
                 /// The total number of glyphs in this enum
                 pub const TOTAL_GLYPHS: usize = #n_glyphs;
 
+                /// All the glyphs in this enum
+                #[allow(clippy::large_stack_arrays)]
+                pub const ALL: [Self; #n_glyphs] = [
+                    #( Self :: #all_variants, )*
+                ];
+
+                /// The codepoints of all the glyphs in this enum, in the same order as [`Self::ALL`]
+                #[allow(clippy::unreadable_literal)]
+                #[allow(clippy::large_stack_arrays)]
+                pub const CODEPOINTS: [u32; #n_glyphs] = [
+                    #( #all_codepoints, )*
+                ];
+
+                /// Returns the postscript name and codepoint of every glyph in this enum, in the same order as [`Self::ALL`]
+                /// Useful for building a search index without loading the font
+                #[allow(clippy::unreadable_literal)]
+                #[allow(clippy::large_stack_arrays)]
+                #[must_use]
+                pub fn entries() -> &'static [(&'static str, u32)] {
+                    const ENTRIES: [(&str, u32); #n_glyphs] = [
+                        #( (#entry_names, #entry_codepoints), )*
+                    ];
+                    &ENTRIES
+                }
+
                 /// Returns the postscript name of the glyph
                 #[allow(clippy::too_many_lines)]
                 #[allow(clippy::match_same_arms)]
@@ -132,6 +293,49 @@ impl FontCategoryDesc {
                     }
                 }
 
+                /// Returns the horizontal advance width of the glyph, in font units
+                #[allow(clippy::too_many_lines)]
+                #[allow(clippy::match_same_arms)]
+                #[allow(clippy::unreadable_literal)]
+                #[must_use]
+                pub fn advance_width(&self) -> u16 {
+                    match *self as u32 {
+                        #( #codepoints => #advance_widths, )*
+                        _ => 0,
+                    }
+                }
+
+                /// Returns the left side bearing of the glyph, in font units
+                #[allow(clippy::too_many_lines)]
+                #[allow(clippy::match_same_arms)]
+                #[allow(clippy::unreadable_literal)]
+                #[must_use]
+                pub fn left_side_bearing(&self) -> i16 {
+                    match *self as u32 {
+                        #( #codepoints => #bearings, )*
+                        _ => 0,
+                    }
+                }
+
+                /// Returns the name of the unicode block containing the glyph, such as `Private Use Area`
+                #[allow(clippy::too_many_lines)]
+                #[allow(clippy::unreadable_literal)]
+                #[must_use]
+                pub fn unicode_range(&self) -> &'static str {
+                    match *self as u32 {
+                        #( #( #block_codepoints )|* => #block_names, )*
+                        _ => "",
+                    }
+                }
+
+                #(
+                    #svg_method
+                )*
+
+                #(
+                    #raw_codepoint_method
+                )*
+
                 #(
                     #injection
                 )*
@@ -143,6 +347,20 @@ impl FontCategoryDesc {
                 }
             }
 
+            impl TryFrom<char> for #identifier {
+                type Error = char;
+
+                /// Returns the glyph for the character, or the character itself if it is not in this enum
+                #[allow(clippy::too_many_lines)]
+                #[allow(clippy::unreadable_literal)]
+                fn try_from(value: char) -> Result<Self, char> {
+                    match value as u32 {
+                        #( #codepoints => Ok(Self :: #variant_idents), )*
+                        _ => Err(value),
+                    }
+                }
+            }
+
             impl From<&#identifier> for char {
                 fn from(value: &#identifier) -> Self {
                     (*value).into()
@@ -155,6 +373,12 @@ impl FontCategoryDesc {
                 }
             }
 
+            impl From<#identifier> for String {
+                fn from(value: #identifier) -> Self {
+                    char::from(value).to_string()
+                }
+            }
+
             impl From<&#identifier> for u32 {
                 fn from(value: &#identifier) -> Self {
                     *value as u32
@@ -1,6 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
+use super::CodegenOptions;
 use crate::font::Glyph;
 
 /// Describes a glyph within a font
@@ -9,32 +10,66 @@ pub struct GlyphDesc {
     identifier: String,
     name: String,
     codepoint: u32,
+    raw_codepoint: u32,
+    advance_width: u16,
+    left_side_bearing: i16,
     comments: Vec<String>,
+    preview: Option<String>,
+    svg: Option<String>,
+    sidecar_dir: Option<String>,
 }
 impl GlyphDesc {
     /// Create a new glyph description from an identifier and a glyph
     #[must_use]
-    pub fn new(identifier: &str, glyph: &Glyph) -> Self {
-        let identifier = identifier.to_string();
+    pub fn new(identifier: impl Into<String>, glyph: &Glyph) -> Self {
+        Self::with_options(identifier, glyph, &CodegenOptions::default())
+    }
+
+    /// Create a new glyph description from an identifier and a glyph, using the specified codegen options
+    ///
+    /// # Panics
+    /// Panics if the glyph has no codepoint, such as `.notdef` - generated variants are keyed by codepoint
+    #[must_use]
+    #[cfg_attr(not(feature = "extended-svg"), allow(unused_variables))]
+    pub fn with_options(
+        identifier: impl Into<String>,
+        glyph: &Glyph,
+        options: &CodegenOptions,
+    ) -> Self {
+        let identifier = identifier.into();
         let name = glyph.name().to_string();
-        let codepoint = glyph.codepoint();
-        let uni_range = glyph.unicode_range();
+        let codepoint = glyph.codepoint().expect("Glyph has no codepoint");
+        let uni_range = crate::unicode_range::unicode_range(codepoint);
 
         let comments = vec![
-            format!("`{name} (U+{codepoint:04X})`  "),
+            format!("`{name} ({})`  ", crate::font::codepoint_hex(codepoint)),
             format!("Unicode range: {uni_range}"),
-            #[cfg(feature = "extended-svg")]
-            format!(
-                "\n\n![Preview Glyph]({})",
-                glyph.svg_dataimage_url().unwrap_or_default()
-            ),
         ];
 
+        #[allow(unused_mut)]
+        let (mut preview, mut svg) = (None, None);
+
+        #[cfg(feature = "extended-svg")]
+        if !options.skip_previews {
+            let svg_preview = glyph.svg_preview();
+            preview = Some(format!(
+                "\n\n![Preview Glyph]({})",
+                crate::svg::svg_dataimage_url(&svg_preview).unwrap_or_default()
+            ));
+            svg = Some(svg_preview);
+        }
+
         Self {
             identifier,
             name,
             codepoint,
+            raw_codepoint: codepoint,
+            advance_width: glyph.advance_width(),
+            left_side_bearing: glyph.left_side_bearing(),
             comments,
+            preview,
+            svg,
+            sidecar_dir: None,
         }
     }
 
@@ -44,12 +79,42 @@ impl GlyphDesc {
         &self.name
     }
 
-    /// Get the codepoint of the glyph
+    /// Get the codepoint of the glyph, as used in the generated code
     #[must_use]
     pub fn codepoint(&self) -> u32 {
         self.codepoint
     }
 
+    /// Get the codepoint of the glyph as it appears in the font, before any normalization
+    #[must_use]
+    pub fn raw_codepoint(&self) -> u32 {
+        self.raw_codepoint
+    }
+
+    /// Set the codepoint used for the glyph in the generated code  
+    /// The original codepoint remains available from [`GlyphDesc::raw_codepoint`]
+    pub fn set_codepoint(&mut self, codepoint: u32) {
+        self.codepoint = codepoint;
+    }
+
+    /// Get the horizontal advance width of the glyph, in font units
+    #[must_use]
+    pub fn advance_width(&self) -> u16 {
+        self.advance_width
+    }
+
+    /// Get the left side bearing of the glyph, in font units
+    #[must_use]
+    pub fn left_side_bearing(&self) -> i16 {
+        self.left_side_bearing
+    }
+
+    /// Get the SVG preview of the glyph, if previews were generated
+    #[must_use]
+    pub fn svg(&self) -> Option<&str> {
+        self.svg.as_deref()
+    }
+
     /// Get the identifier of the glyph
     #[must_use]
     pub fn identifier(&self) -> &str {
@@ -61,6 +126,46 @@ impl GlyphDesc {
         self.identifier = identifier;
     }
 
+    /// Move the SVG previews of the glyph into sidecar files, in a directory relative to `OUT_DIR`  
+    /// The generated code will reference them with `include_str!` - see [`GlyphDesc::sidecar_files`]
+    pub fn set_sidecar_dir(&mut self, dir: &str) {
+        self.sidecar_dir = Some(dir.to_string());
+    }
+
+    /// Get the sidecar files for this glyph, as paths relative to `OUT_DIR` and their contents  
+    /// Empty unless [`GlyphDesc::set_sidecar_dir`] was used
+    #[must_use]
+    pub fn sidecar_files(&self) -> Vec<(String, String)> {
+        let mut files = Vec::with_capacity(2);
+        if let (Some(path), Some(preview)) = (self.sidecar_path("md"), &self.preview) {
+            files.push((path, preview.clone()));
+        }
+        if let (Some(path), Some(svg)) = (self.sidecar_path("svg"), &self.svg) {
+            files.push((path, svg.clone()));
+        }
+
+        files
+    }
+
+    /// Generate the expression for the SVG preview of the glyph, if there is one  
+    /// Either a string literal, or an `include_str!` of the sidecar file
+    #[must_use]
+    pub fn svg_tokens(&self) -> Option<TokenStream> {
+        let svg = self.svg.as_ref()?;
+        if let Some(path) = self.sidecar_path("svg") {
+            Some(quote! { include_str!(concat!(env!("OUT_DIR"), "/", #path)) })
+        } else {
+            Some(quote! { #svg })
+        }
+    }
+
+    /// Returns the path of a sidecar file for this glyph, relative to `OUT_DIR`, if sidecar files are in use
+    fn sidecar_path(&self, extension: &str) -> Option<String> {
+        let dir = self.sidecar_dir.as_ref()?;
+        let codepoint = crate::font::codepoint_hex(self.codepoint);
+        Some(format!("{dir}/{codepoint}.{extension}"))
+    }
+
     /// Generate code for the glyph
     #[must_use]
     pub fn codegen(&self) -> TokenStream {
@@ -68,8 +173,18 @@ impl GlyphDesc {
         let comments = &self.comments;
         let codepoint = self.codepoint;
 
+        let preview = self.preview.as_ref().map(|preview| {
+            if let Some(path) = self.sidecar_path("md") {
+                quote! { #[doc = include_str!(concat!(env!("OUT_DIR"), "/", #path))] }
+            } else {
+                quote! { #[doc = #preview] }
+            }
+        });
+        let preview = preview.iter();
+
         quote! {
             #( #[doc = #comments] )*
+            #( #preview )*
             #identifier = #codepoint,
         }
     }
@@ -1,45 +1,113 @@
 use crate::font::Glyph;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+/// The casing used for the identifiers of generated enum variants  
+/// Category names are always `PascalCase`, since they name types
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IdentifierCase {
+    /// `ArrowLeft` - the usual style for enum variants
+    #[default]
+    Pascal,
+
+    /// `arrow_left`
+    Snake,
+
+    /// `ARROW_LEFT`
+    ScreamingSnake,
+}
+impl IdentifierCase {
+    /// Converts a `PascalCase` identifier, as produced by [`ToIdentExt::to_identifier`], into this case  
+    /// Snake case identifiers that would be keywords are prefixed with `_`
+    #[must_use]
+    pub fn apply(self, identifier: &str) -> String {
+        if self == Self::Pascal {
+            return identifier.to_string();
+        }
+
+        //
+        // Split words on lower-to-upper boundaries, and before the last capital of an acronym - `HTMLFile` becomes `html_file`
+        let chars: Vec<char> = identifier.chars().collect();
+        let mut snake = String::with_capacity(identifier.len() + 4);
+        for (i, c) in chars.iter().enumerate() {
+            if c.is_uppercase() && i > 0 {
+                let prev = chars[i - 1];
+                let next_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+                if prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_lower)
+                {
+                    snake.push('_');
+                }
+            }
+            snake.extend(c.to_lowercase());
+        }
+
+        match self {
+            Self::Snake if RUST_KEYWORDS.binary_search(&snake.as_str()).is_ok() => {
+                format!("_{snake}")
+            }
+            Self::ScreamingSnake => snake.to_uppercase(),
+            _ => snake,
+        }
+    }
+
+    /// Joins two identifiers already in this case into a single identifier  
+    /// A leading `_` on the second identifier is dropped
+    #[must_use]
+    pub fn join(self, first: &str, second: &str) -> String {
+        let second = second.strip_prefix('_').unwrap_or(second);
+        match self {
+            Self::Pascal => format!("{first}{second}"),
+            Self::Snake | Self::ScreamingSnake => format!("{first}_{second}"),
+        }
+    }
+}
 
 /// Maps a set of glyphs to categories with identifiers
-pub fn to_categories(glyphs: &[Glyph]) -> HashMap<String, HashMap<String, Glyph>> {
-    let mut categories = HashMap::new();
+pub fn to_categories<'a>(
+    glyphs: impl IntoIterator<Item = &'a Glyph>,
+    case: IdentifierCase,
+) -> BTreeMap<String, BTreeMap<String, &'a Glyph>> {
+    let mut categories = BTreeMap::new();
     for glyph in glyphs {
         let (category, name) = glyph.name().to_category();
         let category = category.unwrap_or_else(|| "Other".to_string());
 
-        let identifier = uniquify(&name, |id| {
+        let identifier = uniquify(&case.apply(&name), case, |id| {
             categories
                 .get(&category)
-                .is_none_or(|c: &HashMap<String, Glyph>| !c.contains_key(id))
+                .is_none_or(|c: &BTreeMap<String, &'a Glyph>| !c.contains_key(id))
         });
 
-        let category = categories.entry(category).or_insert_with(HashMap::new);
-        category.insert(identifier, glyph.clone());
+        let category = categories.entry(category).or_insert_with(BTreeMap::new);
+        category.insert(identifier, glyph);
     }
 
     categories
 }
 
 /// Maps a set of glyphs to identifiers, checking for duplicates
-pub fn to_identifiers(glyphs: &[Glyph]) -> HashMap<String, Glyph> {
-    let mut identifiers = HashMap::new();
+pub fn to_identifiers<'a>(
+    glyphs: impl IntoIterator<Item = &'a Glyph>,
+    case: IdentifierCase,
+) -> BTreeMap<String, &'a Glyph> {
+    let mut identifiers = BTreeMap::new();
     for glyph in glyphs {
-        let mut identifier = glyph.name().to_identifier();
+        let mut identifier = case.apply(&glyph.name().to_identifier());
 
         // Check for dupes
-        identifier = uniquify(&identifier, |id| !identifiers.contains_key(id));
-        identifiers.insert(identifier, glyph.clone());
+        identifier = uniquify(&identifier, case, |id| !identifiers.contains_key(id));
+        identifiers.insert(identifier, glyph);
     }
 
     identifiers
 }
 
-/// Generates a unique identifier from an identifier
-pub fn uniquify<F: Fn(&str) -> bool>(name: &str, is_unique: F) -> String {
+/// Generates a unique identifier from an identifier in the given case
+pub fn uniquify<F: Fn(&str) -> bool>(name: &str, case: IdentifierCase, is_unique: F) -> String {
     let mut identifier = name.to_string();
     if !is_unique(&identifier) {
-        identifier.push_str("Alt");
+        identifier = case.join(&identifier, &case.apply("Alt"));
 
         // Check for dupes again until we find a unique identifier
         if !is_unique(&identifier) {
@@ -75,8 +143,8 @@ pub trait ToIdentExt {
     /// Returns the prefix and the rest of the font string
     fn to_category(&self) -> (Option<String>, String);
 
-    /// Merges two identifiers into a single identifier
-    fn merge_identifiers(&self, other: &str) -> String;
+    /// Merges a category name with an identifier in the given case, into a single identifier
+    fn merge_identifiers(&self, other: &str, case: IdentifierCase) -> String;
 }
 impl ToIdentExt for str {
     fn to_category(&self) -> (Option<String>, String) {
@@ -146,9 +214,8 @@ impl ToIdentExt for str {
         identifier
     }
 
-    fn merge_identifiers(&self, other: &str) -> String {
-        let other = other.strip_prefix('_').unwrap_or(other);
-        format!("{self}{other}")
+    fn merge_identifiers(&self, other: &str, case: IdentifierCase) -> String {
+        case.join(&case.apply(self), other)
     }
 }
 
@@ -159,3 +226,51 @@ const RUST_KEYWORDS: &[&str] = &[
     "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
     "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
 ];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FONT: &[u8] = include_bytes!("../../../examples/slick.ttf");
+
+    #[test]
+    fn test_identifier_case() {
+        let cases = [
+            ("arrow_left", "ArrowLeft", "arrow_left", "ARROW_LEFT"),
+            ("html-file2", "HtmlFile2", "html_file2", "HTML_FILE2"),
+            ("HTMLFile", "HTMLFile", "html_file", "HTML_FILE"),
+            ("500px", "_500px", "_500px", "_500PX"),
+            ("type", "Type", "_type", "TYPE"),
+        ];
+
+        for (name, pascal, snake, screaming) in cases {
+            let identifier = name.to_identifier();
+            assert_eq!(identifier, pascal);
+            assert_eq!(IdentifierCase::Pascal.apply(&identifier), pascal);
+            assert_eq!(IdentifierCase::Snake.apply(&identifier), snake);
+            assert_eq!(IdentifierCase::ScreamingSnake.apply(&identifier), screaming);
+        }
+    }
+
+    #[test]
+    fn test_deterministic_order() {
+        let font = crate::font::Font::new(FONT).unwrap();
+        let identifiers = to_identifiers(font.glyphs(), IdentifierCase::Pascal);
+        let again = to_identifiers(font.glyphs().iter().rev(), IdentifierCase::Pascal);
+        assert!(identifiers.keys().is_sorted());
+        assert!(identifiers.keys().eq(again.keys()));
+    }
+
+    #[test]
+    fn test_uniquify_case() {
+        let taken = ["arrow", "arrow_alt"];
+        let id = uniquify("arrow", IdentifierCase::Snake, |id| !taken.contains(&id));
+        assert_eq!(id, "arrow_alt2");
+
+        let id = uniquify("Arrow", IdentifierCase::Pascal, |id| id != "Arrow");
+        assert_eq!(id, "ArrowAlt");
+
+        let merged = "Fa".merge_identifiers("_500PX", IdentifierCase::ScreamingSnake);
+        assert_eq!(merged, "FA_500PX");
+    }
+}
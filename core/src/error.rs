@@ -1,4 +1,5 @@
 //! Error type and related utilities
+pub use crate::raw::ttf::Tag;
 
 /// Result type for parsing
 pub type ParseResult<T> = Result<T, ParseError>;
@@ -30,6 +31,15 @@ pub enum ParseError {
         name: &'static str,
     },
 
+    /// A table, or a format within it, that is not supported
+    Unsupported {
+        /// Tag of the table containing the unsupported data
+        table: Tag,
+
+        /// Description of what is unsupported
+        detail: String,
+    },
+
     /// Error while parsing
     Parse {
         /// Byte position of the error in the data
@@ -76,6 +86,10 @@ impl std::fmt::Display for ParseError {
             ParseError::InvalidValue { pos, value, name } => {
                 write!(f, "Invalid value {value:#0x} at {pos} while parsing {name}")
             }
+            ParseError::Unsupported { table, detail } => {
+                let table = String::from_utf8_lossy(table);
+                write!(f, "Unsupported {table} table: {detail}")
+            }
             ParseError::Parse { pos, message } => {
                 write!(f, "Error at {pos}: {message}")
             }
@@ -4,27 +4,77 @@
 //!
 //! The `Glyph` struct contains information about a single glyph in a font:
 //! - Unicode codepoint
+//! - Glyph index
 //! - Postscript name
+//! - Advance width
 //! - Outline data
+//! - Color layers
 //!
 #![allow(clippy::indexing_slicing)]
 #![allow(clippy::cast_possible_truncation)]
 pub use crate::raw::ttf::NameKind as StringKind;
+pub use crate::raw::ttf::PlatformType;
+pub use crate::raw::ttf::Rgba;
+pub use crate::raw::ttf::{Component, CompoundGlyf, Contour, Point};
+pub use crate::svg::{FillRule, SvgOptions, SvgStroke};
 use crate::{
-    error::ParseResult,
-    raw::ttf::{GlyfOutline, SimpleGlyf, TrueTypeFont},
-    svg::SvgExt,
+    error::{ParseError, ParseResult},
+    raw::ttf::{
+        AvarTable, CmapSubtable, FvarTable, GlyfOutline, HdmxTable, SimpleGlyf, TrueTypeFont,
+    },
+    reader::BinaryReader,
+    svg::{escape_xml, wrap_svg_component, PartialSvgExt, SvgExt, SvgProperties},
 };
 use std::{
     borrow::Cow,
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+    time::{Duration, SystemTime},
 };
 
+/// Seconds between the TrueType epoch, 1904-01-01 00:00 UTC, and the unix epoch
+const TRUETYPE_EPOCH_OFFSET: i64 = 2_082_844_800;
+
+/// Color used for layers drawn in the text foreground color, which can't be known ahead of time
+const FOREGROUND: Rgba = Rgba {
+    r: 0,
+    g: 0,
+    b: 0,
+    a: 0xFF,
+};
+
+/// Options controlling how a font is parsed
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseOptions {
+    /// If true, the original font data is kept after parsing, and can be accessed with [`Font::data`]  
+    /// Off by default, to avoid holding a second copy of the font in memory
+    pub retain_data: bool,
+
+    /// If true, unsupported subtables, missing required tables (cmap, glyf, head), duplicate tables, subtables that do not match their declared length, invalid glyph offsets, inverted cmap segments, and undecodable data are errors  
+    /// Off by default, where such data is skipped or decoded lossily instead
+    pub strict: bool,
+}
+
 /// A parsed font, with access to its glyphs and stored strings
+///
+/// The parsed data is shared between clones, so cloning a font is cheap
 #[derive(Debug, Clone)]
 pub struct Font {
-    glyphs: Vec<Glyph>,
-    strings: HashMap<StringKind, String>,
+    glyphs: Arc<[Glyph]>,
+    strings: Arc<HashMap<StringKind, String>>,
+    codepoint_map: Arc<BTreeMap<u32, u16>>,
+    is_monospaced: bool,
+    italic_angle: f32,
+    underline: (i16, i16),
+    num_palettes: usize,
+    device_advances: Arc<HdmxTable>,
+    variation_axes: Arc<[VariationAxis]>,
+    cmap_subtables: Arc<[CmapSubtableInfo]>,
+    parse_failures: Arc<[u16]>,
+    is_signed: bool,
+    created: Option<i64>,
+    modified: Option<i64>,
+    data: Option<Arc<[u8]>>,
 }
 impl Font {
     /// Creates a new font from the given font data
@@ -32,35 +82,180 @@ impl Font {
     /// # Errors
     /// Returns an error if the font data is invalid or cannot be parsed
     pub fn new(font_data: &[u8]) -> ParseResult<Self> {
-        let font = TrueTypeFont::new(font_data)?;
-        Ok(font.into())
+        Self::with_options(font_data, ParseOptions::default())
+    }
+
+    /// Creates a new font from the given font data, using the specified parse options
+    ///
+    /// # Errors
+    /// Returns an error if the font data is invalid or cannot be parsed
+    pub fn with_options(font_data: &[u8], options: ParseOptions) -> ParseResult<Self> {
+        Self::parse(font_data, options, None)
+    }
+
+    /// Creates a new font from the given font data, calling `progress` as each glyph is parsed  
+    /// The callback receives the number of glyphs parsed so far, and the total number of glyphs in the font
+    ///
+    /// # Errors
+    /// Returns an error if the font data is invalid or cannot be parsed
+    pub fn new_with_progress(
+        font_data: &[u8],
+        mut progress: impl FnMut(usize, usize),
+    ) -> ParseResult<Self> {
+        Self::parse(font_data, ParseOptions::default(), Some(&mut progress))
+    }
+
+    fn parse(
+        font_data: &[u8],
+        options: ParseOptions,
+        progress: Option<&mut dyn FnMut(usize, usize)>,
+    ) -> ParseResult<Self> {
+        let mut reader = BinaryReader::new(font_data);
+        reader.set_strict(options.strict);
+
+        let font = TrueTypeFont::parse_with_progress(&mut reader, progress)?;
+        let mut font = Self::from(font);
+
+        if options.retain_data {
+            font.data = Some(Arc::from(font_data));
+        }
+
+        Ok(font)
     }
 
     /// Creates a new font from the font file at the specified path
     ///
     /// # Errors
     /// Returns an error if the font data is invalid or cannot be parsed
+    #[cfg(feature = "std-fs")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std-fs")))]
     pub fn from_file(path: impl AsRef<std::path::Path>) -> ParseResult<Self> {
         let font_data = std::fs::read(path)?;
         Self::new(&font_data)
     }
 
+    /// Writes the SVG preview of every glyph to `<identifier>.svg` in the specified directory, creating it if needed  
+    /// Identifiers are generated the same way as the variants of a generated enum
+    ///
+    /// Returns the number of files written
+    ///
+    /// # Errors
+    /// Returns an error if the directory cannot be created, or a file cannot be written
+    #[cfg(all(feature = "std-fs", feature = "codegen"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std-fs", feature = "codegen"))))]
+    pub fn export_svgs(&self, dir: impl AsRef<std::path::Path>) -> std::io::Result<usize> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+
+        let case = crate::codegen::IdentifierCase::default();
+        let identifiers = crate::codegen::to_identifiers(self.glyphs.iter(), case);
+        for (identifier, glyph) in &identifiers {
+            let path = dir.join(format!("{identifier}.svg"));
+            std::fs::write(path, glyph.svg_preview())?;
+        }
+
+        Ok(identifiers.len())
+    }
+
+    /// Returns the number of glyphs in each category, by category name  
+    /// Categories are derived from glyph names the same way as during code generation - glyphs without a prefix are in `Other`
+    #[cfg(feature = "codegen")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "codegen")))]
+    #[must_use]
+    pub fn categories(&self) -> HashMap<String, usize> {
+        crate::codegen::to_categories(
+            self.glyphs.iter(),
+            crate::codegen::IdentifierCase::default(),
+        )
+        .into_iter()
+        .map(|(name, glyphs)| (name, glyphs.len()))
+        .collect()
+    }
+
+    /// Generates the code for an enum of the glyphs in the font, named `name`, using the default codegen options  
+    /// Shorthand for `FontDesc::from_font(name, font, false).codegen(None)` - see [`crate::codegen::FontDesc`] for more control
+    #[cfg(feature = "codegen")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "codegen")))]
+    #[must_use]
+    pub fn to_enum_tokens(&self, name: &str) -> proc_macro2::TokenStream {
+        crate::codegen::FontDesc::from_font(name, self, false).codegen(None)
+    }
+
+    /// Returns the original font data, if it was retained using [`ParseOptions::retain_data`]  
+    /// Useful for handing the font to other libraries, such as `cosmic-text`
+    #[must_use]
+    pub fn data(&self) -> Option<&[u8]> {
+        self.data.as_deref()
+    }
+
+    /// Registers the font with a `fontdb` database, sharing the retained font data instead of copying it  
+    /// Requires the font data to have been retained using [`ParseOptions::retain_data`]
+    ///
+    /// Returns the IDs assigned by the database, or `None` if the font data was not retained
+    #[cfg(feature = "fontdb")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fontdb")))]
+    pub fn load_into_fontdb(&self, db: &mut fontdb::Database) -> Option<Vec<fontdb::ID>> {
+        let data = self.data.clone()?;
+        let ids = db.load_font_source(fontdb::Source::Binary(Arc::new(data)));
+        Some(ids.to_vec())
+    }
+
     /// Returns the string with the specified kind, if it exists
     #[must_use]
     pub fn string(&self, kind: StringKind) -> Option<&str> {
         self.strings.get(&kind).map(String::as_str)
     }
 
+    /// Returns the PostScript name of the font, used to reference it in OS APIs and CSS `local()`  
+    /// Surrounding whitespace is trimmed
+    #[must_use]
+    pub fn postscript_name(&self) -> Option<&str> {
+        self.string(StringKind::PostscriptName).map(str::trim)
+    }
+
+    /// Returns the sample text suggested by the font's designer, for use in font specimens
+    #[must_use]
+    pub fn sample_text(&self) -> Option<&str> {
+        self.string(StringKind::SampleText)
+    }
+
     /// Returns all the strings in the font
     #[must_use]
     pub fn strings(&self) -> &HashMap<StringKind, String> {
         &self.strings
     }
 
+    /// Returns all the strings in the font, sorted by their name id  
+    /// Useful for printing a complete table of the font's metadata
+    #[must_use]
+    pub fn all_strings(&self) -> Vec<(StringKind, &str)> {
+        let mut strings: Vec<_> = self
+            .strings
+            .iter()
+            .map(|(kind, string)| (*kind, string.as_str()))
+            .collect();
+        strings.sort_by_key(|(kind, _)| *kind as u16);
+        strings
+    }
+
+    /// Returns the complete mapping of unicode codepoints to glyph indices from the font's CMAP table  
+    /// Unlike [`Font::glyphs`], this includes codepoints mapped to unnamed glyphs
+    #[must_use]
+    pub fn codepoint_map(&self) -> &BTreeMap<u32, u16> {
+        &self.codepoint_map
+    }
+
     /// Returns the glyph with the specified unicode codepoint, if it exists
     #[must_use]
     pub fn glyph(&self, codepoint: u32) -> Option<&Glyph> {
-        self.glyphs.iter().find(|g| g.codepoint == codepoint)
+        self.glyphs.iter().find(|g| g.codepoint == Some(codepoint))
+    }
+
+    /// Returns the glyph with the specified glyph index, if it exists  
+    /// Glyph indices are shared with other TTF libraries, such as `swash` and `cosmic-text`
+    #[must_use]
+    pub fn glyph_by_id(&self, glyph_id: u16) -> Option<&Glyph> {
+        self.glyphs.iter().find(|g| g.index == glyph_id)
     }
 
     /// Returns the glyph with the specified postscript name, if it exists
@@ -69,11 +264,453 @@ impl Font {
         self.glyphs.iter().find(|g| g.name == name)
     }
 
-    /// Returns the glyphs in the font
+    /// Returns the glyph with the specified postscript name, ignoring ASCII case, if it exists  
+    /// Useful for user-facing search - use [`Font::glyph_named`] for exact lookups
+    #[must_use]
+    pub fn glyph_named_ci(&self, name: &str) -> Option<&Glyph> {
+        self.glyphs
+            .iter()
+            .find(|g| g.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the glyphs in the font, in glyph index order
     #[must_use]
     pub fn glyphs(&self) -> &[Glyph] {
         &self.glyphs
     }
+
+    /// Returns the glyphs in the font, sorted by unicode codepoint - glyphs without one, such as `.notdef`, come first  
+    /// Useful for displaying glyphs in a natural order - see [`Font::glyphs`] for glyph index order
+    #[must_use]
+    pub fn glyphs_sorted(&self) -> Vec<&Glyph> {
+        let mut glyphs: Vec<_> = self.glyphs.iter().collect();
+        glyphs.sort_by_key(|glyph| glyph.codepoint);
+        glyphs
+    }
+
+    /// Sets the options used to render the SVG previews of every glyph in the font, such as the margin around the outline
+    pub fn set_svg_options(&mut self, options: SvgOptions) {
+        let mut glyphs = self.glyphs.to_vec();
+        for glyph in &mut glyphs {
+            glyph.set_svg_options(options);
+        }
+
+        self.glyphs = glyphs.into();
+    }
+
+    /// Moves every glyph in the font to sequential codepoints starting at `base`, in glyph index order  
+    /// Useful for relocating an icon font into a private-use range, such as `U+E000`, before combining it with others
+    ///
+    /// Glyph names are unchanged. The codepoint map is rebuilt from the relocated glyphs  
+    /// Surrogates are skipped, and glyphs without a codepoint, such as `.notdef`, are never moved
+    ///
+    /// # Errors
+    /// Returns the number of glyphs that would not fit if the range runs past `U+10FFFF` - the font is left unchanged
+    pub fn remap_codepoints(&mut self, base: u32) -> Result<(), usize> {
+        let mut codepoints = (base..=u32::from(char::MAX)).filter(|c| char::from_u32(*c).is_some());
+        let mut glyphs = self.glyphs.to_vec();
+        let mut codepoint_map = BTreeMap::new();
+        let mapped: Vec<_> = glyphs
+            .iter_mut()
+            .filter(|g| g.codepoint.is_some())
+            .collect();
+        let total = mapped.len();
+        for (i, glyph) in mapped.into_iter().enumerate() {
+            let Some(codepoint) = codepoints.next() else {
+                return Err(total - i);
+            };
+
+            glyph.codepoint = Some(codepoint);
+            codepoint_map.insert(codepoint, glyph.index);
+        }
+
+        self.glyphs = glyphs.into();
+        self.codepoint_map = Arc::new(codepoint_map);
+        Ok(())
+    }
+
+    /// Returns true if the font is marked as monospaced
+    #[must_use]
+    pub fn is_monospaced(&self) -> bool {
+        self.is_monospaced
+    }
+
+    /// Returns the angle of the font's italic slant, in counter-clockwise degrees from vertical, according to its `post` table  
+    /// Upright fonts return 0, and fonts leaning to the right return a negative angle - useful for faux-italic shearing
+    #[must_use]
+    pub fn italic_angle(&self) -> f32 {
+        self.italic_angle
+    }
+
+    /// Returns the suggested underline for the font, as `(position, thickness)` in font units, according to its `post` table  
+    /// The position is the distance from the baseline to the top of the underline, and is negative below the baseline
+    #[must_use]
+    pub fn underline(&self) -> (i16, i16) {
+        self.underline
+    }
+
+    /// Returns the number of color palettes in the font  
+    /// Fonts without color glyphs have no palettes
+    #[must_use]
+    pub fn num_palettes(&self) -> usize {
+        self.num_palettes
+    }
+
+    /// Returns when the font was created, according to its `head` table  
+    /// Returns `None` if the font has no `head` table
+    #[must_use]
+    pub fn created(&self) -> Option<SystemTime> {
+        self.created.and_then(truetype_time)
+    }
+
+    /// Returns when the font was last modified, according to its `head` table  
+    /// Returns `None` if the font has no `head` table
+    #[must_use]
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified.and_then(truetype_time)
+    }
+
+    /// Returns the advance width of the glyph with the specified glyph index, in whole pixels at `ppem` pixels per em  
+    /// Read from the font's `hdmx` table, for pixel-accurate layout at specific sizes
+    ///
+    /// Returns `None` if the font has no device metrics for that size or glyph
+    #[must_use]
+    pub fn device_advance(&self, glyph_id: u16, ppem: u8) -> Option<u8> {
+        self.device_advances.get_advance(glyph_id, ppem)
+    }
+
+    /// Returns the variation axes of the font, such as weight or width, if it is a variable font  
+    /// Each axis includes its `avar` segment map, describing how the axis is scaled between its extremes
+    #[must_use]
+    pub fn variation_axes(&self) -> &[VariationAxis] {
+        &self.variation_axes
+    }
+
+    /// Returns true if the font is a variable font, with at least one variation axis  
+    /// Static fonts return false - see [`Font::variation_axes`] for the axes themselves
+    #[must_use]
+    pub fn is_variable(&self) -> bool {
+        !self.variation_axes.is_empty()
+    }
+
+    /// Returns a description of each subtable in the font's `cmap` table, in the order they appear  
+    /// Useful for diagnosing which encodings a font supports, such as multi-script Macintosh fonts
+    #[must_use]
+    pub fn cmap_subtables(&self) -> &[CmapSubtableInfo] {
+        &self.cmap_subtables
+    }
+
+    /// Returns the ids of glyphs whose outline could not be parsed, and were left empty instead  
+    /// Useful for finding out why a glyph is blank - in strict mode these are errors instead, see [`ParseOptions::strict`]
+    #[must_use]
+    pub fn parse_failures(&self) -> &[u16] {
+        &self.parse_failures
+    }
+
+    /// Returns true if the font is digitally signed, meaning it has a `DSIG` table  
+    /// The signature itself is not verified
+    #[must_use]
+    pub fn is_signed(&self) -> bool {
+        self.is_signed
+    }
+
+    /// Returns true if all the glyphs in the font share the same advance width  
+    /// Unlike [`Font::is_monospaced`], this is calculated from the glyph metrics rather than trusting the font's own flag
+    ///
+    /// Zero-width glyphs, such as combining marks, are ignored
+    #[must_use]
+    pub fn is_uniform_advance(&self) -> bool {
+        let mut advances = self
+            .glyphs
+            .iter()
+            .map(Glyph::advance_width)
+            .filter(|advance| *advance != 0);
+
+        match advances.next() {
+            Some(first) => advances.all(|advance| advance == first),
+            None => false,
+        }
+    }
+
+    /// Returns the union of the bounding boxes of every glyph outline in the font, as `(x_min, y_min, x_max, y_max)` in font units  
+    /// Unlike the bounding box in the font's `head` table, this only covers the glyphs that are actually drawn
+    ///
+    /// Empty outlines and SVG-backed glyphs are ignored - if there are no outlines at all, the bounds are all 0
+    #[must_use]
+    pub fn outline_bounds(&self) -> (i16, i16, i16, i16) {
+        let outlines = self.glyphs.iter().filter_map(|glyph| match &glyph.preview {
+            GlyphPreview::Ttf(outline) if !outline.contours.is_empty() => Some(outline),
+            _ => None,
+        });
+
+        let mut bounds = None;
+        for outline in outlines {
+            let (x_min, y_min, x_max, y_max) =
+                bounds.unwrap_or((i16::MAX, i16::MAX, i16::MIN, i16::MIN));
+            bounds = Some((
+                x_min.min(outline.x.0),
+                y_min.min(outline.y.0),
+                x_max.max(outline.x.1),
+                y_max.max(outline.y.1),
+            ));
+        }
+
+        bounds.unwrap_or_default()
+    }
+
+    /// Renders every glyph in the font onto a single SVG sheet, in a grid `columns` cells wide  
+    /// Each cell fits [`Font::outline_bounds`], with the glyph's name written underneath
+    ///
+    /// SVG-backed glyphs are shown as empty, labelled cells
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn render_specimen(&self, columns: usize) -> String {
+        let columns = columns.max(1);
+        let rows = self.glyphs.len().div_ceil(columns).max(1);
+
+        //
+        // Size the cells to fit every outline, with room for a label
+        let (x_min, y_min, x_max, y_max) = self.outline_bounds();
+        let glyph_width = f32::from(x_max) - f32::from(x_min);
+        let glyph_height = f32::from(y_max) - f32::from(y_min);
+        let padding = glyph_width.max(glyph_height).max(10.0) / 10.0;
+        let label_size = padding * 1.5;
+        let cell_width = glyph_width + 2.0 * padding;
+        let cell_height = glyph_height + 3.0 * padding + label_size;
+
+        let mut cells = Vec::with_capacity(self.glyphs.len() * 2);
+        for (i, glyph) in self.glyphs.iter().enumerate() {
+            let x = (i % columns) as f32 * cell_width;
+            let y = (i / columns) as f32 * cell_height;
+
+            //
+            // Outlines are drawn with y flipped, so the top of the bounds is at -y_max
+            if let GlyphPreview::Ttf(outline) = &glyph.preview {
+                let dx = x + padding - f32::from(x_min);
+                let dy = y + padding + f32::from(y_max);
+                let component = outline.as_svg_component();
+                cells.push(format!(
+                    "<g transform='translate({dx} {dy})'>{component}</g>"
+                ));
+            }
+
+            let (label_x, label_y) = (x + cell_width / 2.0, y + cell_height - padding);
+            let name = escape_xml(glyph.name());
+            cells.push(format!(
+                "<text x='{label_x}' y='{label_y}' font-size='{label_size}' font-family='sans-serif' text-anchor='middle'>{name}</text>"
+            ));
+        }
+
+        let properties = SvgProperties {
+            viewbox_position: (0.0, 0.0),
+            viewbox_size: (columns as f32 * cell_width, rows as f32 * cell_height),
+            scale_to: None,
+            scale_to_height: None,
+            margin: None,
+            fill_rule: FillRule::default(),
+            stroke: None,
+        };
+        wrap_svg_component(&properties, &cells.join(""))
+    }
+
+    /// Writes every glyph in the font to `w` as an SVG sprite sheet - a hidden SVG of `<symbol>` elements  
+    /// Each symbol's id is the glyph's postscript name, so it can be drawn with `<use href='#name'/>`
+    ///
+    /// Symbols are written one glyph at a time, so memory use stays low even for very large fonts  
+    /// SVG-backed glyphs are skipped
+    ///
+    /// # Errors
+    /// Returns an error if writing to `w` fails
+    pub fn write_svg_sprite(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        w.write_all(b"<svg xmlns='http://www.w3.org/2000/svg' style='display:none'>")?;
+        for glyph in self
+            .glyphs
+            .iter()
+            .filter(|glyph| glyph.contours().is_some())
+        {
+            w.write_all(glyph.to_svg_symbol(&glyph.name).as_bytes())?;
+        }
+        w.write_all(b"</svg>")
+    }
+
+    /// Returns the SVG preview of every glyph in the font, in glyph index order  
+    /// With the `rayon` feature the glyphs are rendered in parallel - otherwise this falls back to rendering them one at a time
+    #[must_use]
+    pub fn svg_previews_par(&self) -> Vec<String> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            self.glyphs.par_iter().map(Glyph::svg_preview).collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            self.glyphs.iter().map(Glyph::svg_preview).collect()
+        }
+    }
+
+    /// Combines the glyphs of this font with those of another, into a new font
+    ///
+    /// Collisions are resolved in favour of `self`:
+    /// - Glyphs from `other` with a codepoint already in `self` are skipped, as are those without a codepoint, such as `.notdef`
+    /// - Glyphs from `other` with a name already in use are renamed with a numeric suffix - `name_2`, `name_3`, etc
+    /// - Strings missing from `self` are copied from `other`
+    ///
+    /// Glyphs from `other` are renumbered to follow every glyph index used by `self`, along with their components and color layers  
+    /// Glyphs that would not fit in a 16-bit glyph index are skipped
+    ///
+    /// The codepoint map and parse failures cover both fonts, using the new indices  
+    /// Device advances, variation axes and CMAP subtables describe `self` alone
+    ///
+    /// The merged font does not retain any font data, is never signed, and is only monospaced if both fonts are
+    #[must_use]
+    pub fn merge(&self, other: &Font) -> Font {
+        let mut glyphs = self.glyphs.to_vec();
+        let mut codepoints: HashSet<_> = glyphs.iter().filter_map(|g| g.codepoint).collect();
+        let mut names: HashSet<_> = glyphs.iter().map(|g| g.name.to_string()).collect();
+
+        //
+        // First index past every glyph id referenced by `self`
+        let offset = glyphs
+            .iter()
+            .flat_map(Glyph::referenced_ids)
+            .chain(self.codepoint_map.values().copied())
+            .chain(self.parse_failures.iter().copied())
+            .max()
+            .map_or(0, |max| u32::from(max) + 1);
+        let renumber = |glyph_id: u16| u16::try_from(u32::from(glyph_id) + offset).ok();
+
+        let mut codepoint_map = self.codepoint_map.as_ref().clone();
+        for glyph in other.glyphs.iter() {
+            let Some(codepoint) = glyph.codepoint else {
+                continue;
+            };
+            if codepoints.contains(&codepoint) {
+                continue;
+            }
+
+            let mut glyph = glyph.clone();
+            let Some(index) = renumber(glyph.index) else {
+                continue;
+            };
+            glyph.index = index;
+            if let Some(layers) = &mut glyph.color_layers {
+                let Some(renumbered) = layers
+                    .iter()
+                    .map(|(id, colors)| Some((renumber(*id)?, colors.clone())))
+                    .collect()
+                else {
+                    continue;
+                };
+                *layers = renumbered;
+            }
+            if let Some(compound) = &mut glyph.components {
+                let Some(renumbered) = compound
+                    .components
+                    .iter()
+                    .map(|component| {
+                        let mut component = component.clone();
+                        component.glyph_id = renumber(component.glyph_id)?;
+                        Some(component)
+                    })
+                    .collect()
+                else {
+                    continue;
+                };
+                compound.components = renumbered;
+            }
+
+            if names.contains(glyph.name.as_ref()) {
+                let mut suffix = 2;
+                while names.contains(&format!("{}_{suffix}", glyph.name)) {
+                    suffix += 1;
+                }
+                glyph.name = Cow::Owned(format!("{}_{suffix}", glyph.name));
+            }
+
+            codepoints.insert(codepoint);
+            codepoint_map.insert(codepoint, glyph.index);
+            names.insert(glyph.name.to_string());
+            glyphs.push(glyph);
+        }
+
+        //
+        // Unnamed glyphs from `other` keep their codepoints where `self` has none
+        for (codepoint, glyph_id) in other.codepoint_map.iter() {
+            if let Some(glyph_id) = renumber(*glyph_id) {
+                codepoint_map.entry(*codepoint).or_insert(glyph_id);
+            }
+        }
+
+        let parse_failures: Vec<_> = self
+            .parse_failures
+            .iter()
+            .copied()
+            .chain(other.parse_failures.iter().filter_map(|id| renumber(*id)))
+            .collect();
+
+        let mut strings = other.strings.as_ref().clone();
+        strings.extend(self.strings.iter().map(|(k, v)| (*k, v.clone())));
+
+        Font {
+            glyphs: glyphs.into(),
+            strings: Arc::new(strings),
+            codepoint_map: Arc::new(codepoint_map),
+            is_monospaced: self.is_monospaced && other.is_monospaced,
+            italic_angle: self.italic_angle,
+            underline: self.underline,
+            num_palettes: self.num_palettes.max(other.num_palettes),
+            device_advances: Arc::clone(&self.device_advances),
+            variation_axes: Arc::clone(&self.variation_axes),
+            cmap_subtables: Arc::clone(&self.cmap_subtables),
+            parse_failures: parse_failures.into(),
+            is_signed: false,
+            created: self.created,
+            modified: self.modified,
+            data: None,
+        }
+    }
+}
+
+/// Formats a unicode codepoint as `U+XXXX`, padded to at least 4 hex digits
+pub(crate) fn codepoint_hex(codepoint: u32) -> String {
+    format!("U+{codepoint:04X}")
+}
+
+/// Converts a TrueType `LONGDATETIME` into a system time
+fn truetype_time(seconds: i64) -> Option<SystemTime> {
+    let unix = seconds - TRUETYPE_EPOCH_OFFSET;
+    let offset = Duration::from_secs(unix.unsigned_abs());
+    if unix >= 0 {
+        SystemTime::UNIX_EPOCH.checked_add(offset)
+    } else {
+        SystemTime::UNIX_EPOCH.checked_sub(offset)
+    }
+}
+
+impl std::fmt::Display for Font {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let family = self.string(StringKind::FontFamily).unwrap_or("Unknown");
+        write!(f, "{family} ({} glyphs", self.glyphs.len())?;
+        if self.is_monospaced {
+            write!(f, ", monospaced")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl TryFrom<&[u8]> for Font {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> ParseResult<Self> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<Vec<u8>> for Font {
+    type Error = ParseError;
+    fn try_from(value: Vec<u8>) -> ParseResult<Self> {
+        Self::new(&value)
+    }
 }
 
 impl From<TrueTypeFont> for Font {
@@ -82,62 +719,224 @@ impl From<TrueTypeFont> for Font {
         let post = value.post_table;
         let name = value.name_table;
         let glyf = value.glyf_table;
+        let hmtx = value.hmtx_table;
+        let colr = value.colr_table;
+        let cpal = value.cpal_table;
+        let num_palettes = cpal.palettes.len();
+        let is_monospaced = post.is_monospaced;
+        let italic_angle = post.italic_angle;
+        let underline = (post.underline_position, post.underline_thickness);
 
         let mut strings = HashMap::new();
         for record in name.records {
             strings.insert(record.name_id, record.name);
         }
 
+        let cmap_subtables = cmap.tables.iter().map(CmapSubtableInfo::from).collect();
+
+        let mut codepoint_map = BTreeMap::new();
+        for subtable in &cmap.tables {
+            for (glyph_index, codepoint) in &subtable.mappings {
+                // Glyph 0 is used for unmapped codepoints
+                if *glyph_index != 0 {
+                    codepoint_map.insert(*codepoint, *glyph_index);
+                }
+            }
+        }
+
         let mut glyphs = Vec::new();
         let mut codepoint_hash = HashSet::new();
+
+        // Identical outlines are shared between glyphs
+        let mut outlines: HashSet<Arc<SimpleGlyf>> = HashSet::new();
+
+        // Compound components are resolved once, no matter how many glyphs use them
+        let mut resolved = HashMap::new();
         for (glyph_index, name) in post.glyph_names.into_iter().enumerate() {
             let name = Cow::Owned(name);
             let glyph_index = glyph_index as u16;
 
             // Find unicode codepoint, skipping unmapped glyphs
-            let codepoint = cmap.get_codepoint(glyph_index);
-            let codepoint = match codepoint {
-                Some(c) if glyph_index == 0 => c,
-                Some(c) if c != 0xFFFF => c,
+            // Glyph 0 (`.notdef`) is never mapped, but is kept without a codepoint
+            let codepoint = match cmap.get_codepoint(glyph_index) {
+                Some(c) if c != 0xFFFF => Some(c),
+                _ if glyph_index == 0 => None,
                 _ => continue,
             };
 
             // Skip duplicate codepoints
-            if !codepoint_hash.insert(codepoint) {
+            if codepoint.is_some_and(|codepoint| !codepoint_hash.insert(codepoint)) {
                 continue;
             }
 
-            // Get the glyph outline data
-            let outline = match glyf[glyph_index as usize] {
-                GlyfOutline::Simple(ref outline) => outline.clone(),
-                GlyfOutline::Compound(ref outline) => outline.as_simple(&glyf),
+            // Get the glyph outline data - fonts missing their glyf table get empty outlines
+            // Compound glyphs keep their components, alongside the resolved outline
+            let (outline, components) = match glyf.get(glyph_index as usize) {
+                Some(GlyfOutline::Simple(outline)) => (outline.clone(), None),
+                Some(GlyfOutline::Compound(outline)) => (
+                    outline.as_simple_cached(&glyf, &mut resolved),
+                    Some(outline.clone()),
+                ),
+                None => (SimpleGlyf::default(), None),
+            };
+            let outline = if let Some(shared) = outlines.get(&outline) {
+                Arc::clone(shared)
+            } else {
+                let outline = Arc::new(outline);
+                outlines.insert(Arc::clone(&outline));
+                outline
             };
             let preview = GlyphPreview::Ttf(outline);
 
+            let metric = hmtx.get_metric(glyph_index).copied().unwrap_or_default();
+
+            // Store the color of each layer in every palette
+            let color_layers = colr.get_layers(glyph_index).map(|layers| {
+                layers
+                    .iter()
+                    .map(|layer| {
+                        let colors = (0..num_palettes.max(1))
+                            .map(|palette| {
+                                cpal.get_color(palette, layer.palette_index)
+                                    .unwrap_or(FOREGROUND)
+                            })
+                            .collect();
+                        (layer.glyph_id, colors)
+                    })
+                    .collect()
+            });
+
             glyphs.push(Glyph {
                 codepoint,
+                index: glyph_index,
+                advance_width: metric.advance_width,
+                left_side_bearing: metric.left_side_bearing,
                 name,
                 preview,
+                svg_options: SvgOptions::DEFAULT,
+                color_layers,
+                components,
             });
         }
 
-        Self { glyphs, strings }
+        Self {
+            glyphs: glyphs.into(),
+            strings: Arc::new(strings),
+            codepoint_map: Arc::new(codepoint_map),
+            is_monospaced,
+            italic_angle,
+            underline,
+            num_palettes,
+            device_advances: Arc::new(value.hdmx_table),
+            variation_axes: VariationAxis::from_tables(&value.fvar_table, &value.avar_table).into(),
+            cmap_subtables,
+            parse_failures: value.parse_failures.into(),
+            is_signed: value.table_tags.contains(b"DSIG"),
+            created: value.head_table.map(|head| head.created),
+            modified: value.head_table.map(|head| head.modified),
+            data: None,
+        }
+    }
+}
+
+/// A variation axis of a variable font, such as weight or width
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariationAxis {
+    /// The tag identifying the axis, such as `wght` or `wdth`
+    pub tag: String,
+
+    /// The minimum value of the axis
+    pub min: f32,
+
+    /// The default value of the axis
+    pub default: f32,
+
+    /// The maximum value of the axis
+    pub max: f32,
+
+    /// True if the axis is meant to be hidden from users
+    pub hidden: bool,
+
+    /// The id of the axis name in the font's name table
+    pub name_id: u16,
+
+    /// How normalized coordinates on the axis are remapped, as `(from, to)` pairs between -1.0 and 1.0  
+    /// Fonts without an `avar` mapping for the axis report the linear mapping `[(-1, -1), (0, 0), (1, 1)]`
+    pub segment_map: Vec<(f32, f32)>,
+}
+impl VariationAxis {
+    /// The segment map of an axis with no `avar` mapping
+    const LINEAR: [(f32, f32); 3] = [(-1.0, -1.0), (0.0, 0.0), (1.0, 1.0)];
+
+    /// Combines the axes of the `fvar` table with their `avar` segment maps
+    fn from_tables(fvar: &FvarTable, avar: &AvarTable) -> Vec<Self> {
+        fvar.axes
+            .iter()
+            .enumerate()
+            .map(|(i, axis)| {
+                let segment_map = match avar.segment_maps.get(i) {
+                    Some(map) if !map.is_empty() => map
+                        .iter()
+                        .map(|(from, to)| (*from as f32, *to as f32))
+                        .collect(),
+                    _ => Self::LINEAR.to_vec(),
+                };
+
+                Self {
+                    tag: String::from_utf8_lossy(&axis.tag).into_owned(),
+                    min: axis.min_value,
+                    default: axis.default_value,
+                    max: axis.max_value,
+                    hidden: axis.flags & 0x0001 != 0,
+                    name_id: axis.name_id,
+                    segment_map,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Describes a subtable of a font's `cmap` table, which maps character codes to glyphs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CmapSubtableInfo {
+    /// The platform the subtable is for
+    pub platform: PlatformType,
+
+    /// The platform-specific encoding of the subtable
+    pub encoding: u16,
+
+    /// The format of the subtable, such as 4 or 12
+    pub format: u16,
+
+    /// The language of the subtable - only meaningful on the Macintosh platform, where it identifies the script  
+    /// 0 if the subtable is language-independent
+    pub language: u32,
+}
+impl From<&CmapSubtable> for CmapSubtableInfo {
+    fn from(value: &CmapSubtable) -> Self {
+        Self {
+            platform: value.platform,
+            encoding: value.encoding,
+            format: value.format,
+            language: value.language,
+        }
     }
 }
 
 /// A preview of a glyph, either as a TTF outline or SVG image
 #[derive(Debug, Clone)]
 pub enum GlyphPreview {
-    /// TTF formatted glyph data - converted to simple fmt if needed
-    Ttf(SimpleGlyf),
+    /// TTF formatted glyph data - converted to simple fmt if needed  
+    /// Glyphs with identical outlines share the same data
+    Ttf(Arc<SimpleGlyf>),
 
     /// SVG formatted glyph data, as a string
     Svg(Cow<'static, str>),
 }
 impl SvgExt for GlyphPreview {
-    fn to_svg(&self) -> String {
+    fn to_svg_with_options(&self, options: &SvgOptions) -> String {
         match self {
-            Self::Ttf(outline) => outline.to_svg(),
+            Self::Ttf(outline) => outline.to_svg_with_options(options),
             Self::Svg(svg) => svg.to_string(),
         }
     }
@@ -146,37 +945,91 @@ impl SvgExt for GlyphPreview {
 /// A single glyph in a font
 #[derive(Debug, Clone)]
 pub struct Glyph {
-    codepoint: u32,
+    codepoint: Option<u32>,
+    index: u16,
+    advance_width: u16,
+    left_side_bearing: i16,
     name: Cow<'static, str>,
     preview: GlyphPreview,
+    svg_options: SvgOptions,
+    color_layers: Option<Vec<(u16, Vec<Rgba>)>>,
+    components: Option<CompoundGlyf>,
 }
 impl Glyph {
-    /// Creates a new glyph with the specified codepoint, name, and preview data
+    /// Creates a new glyph with the specified codepoint, name, and preview data  
+    /// The glyph index and metrics of the new glyph will be 0
     #[must_use]
     pub const fn new(codepoint: u32, name: &'static str, preview: GlyphPreview) -> Self {
         Self {
-            codepoint,
+            codepoint: Some(codepoint),
+            index: 0,
+            advance_width: 0,
+            left_side_bearing: 0,
             name: Cow::Borrowed(name),
             preview,
+            svg_options: SvgOptions::DEFAULT,
+            color_layers: None,
+            components: None,
         }
     }
 
-    /// Returns the unicode range for the glyph
+    /// Returns the unicode range for the glyph, if it has a codepoint
     #[must_use]
-    pub fn unicode_range(&self) -> &'static str {
-        crate::unicode_range::unicode_range(self.codepoint)
+    pub fn unicode_range(&self) -> Option<&'static str> {
+        self.codepoint.map(crate::unicode_range::unicode_range)
     }
 
-    /// Returns the unicode codepoint for the glyph
+    /// Returns the unicode codepoint for the glyph  
+    /// Glyphs no character maps to, such as `.notdef`, have no codepoint
     #[must_use]
-    pub fn codepoint(&self) -> u32 {
+    pub fn codepoint(&self) -> Option<u32> {
         self.codepoint
     }
 
-    /// Returns the character for the glyph
+    /// Returns the unicode codepoint for the glyph, formatted as `U+XXXX`, if it has one  
+    /// Padded to at least 4 hex digits - codepoints outside the BMP use 5 or 6, such as `U+1F600`
+    #[must_use]
+    pub fn codepoint_hex(&self) -> Option<String> {
+        self.codepoint.map(codepoint_hex)
+    }
+
+    /// Returns the index of the glyph within the font  
+    /// This is the same glyph id used by other TTF libraries, such as `swash` and `cosmic-text`
+    #[must_use]
+    pub fn glyph_id(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the horizontal advance width of the glyph, in font units
+    #[must_use]
+    pub fn advance_width(&self) -> u16 {
+        self.advance_width
+    }
+
+    /// Returns the left side bearing of the glyph, in font units  
+    /// This is the horizontal distance from the glyph's origin to the left edge of its outline
+    #[must_use]
+    pub fn left_side_bearing(&self) -> i16 {
+        self.left_side_bearing
+    }
+
+    /// Returns the character for the glyph  
+    /// Glyphs without a valid codepoint return the replacement character, `U+FFFD`
     #[must_use]
     pub fn char(&self) -> char {
-        std::char::from_u32(self.codepoint).unwrap_or(char::REPLACEMENT_CHARACTER)
+        self.codepoint
+            .and_then(std::char::from_u32)
+            .unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+
+    /// Returns the character for the glyph  
+    /// Unlike [`Glyph::char`], returns the raw codepoint as an error if it is not a valid unicode scalar value
+    ///
+    /// # Errors
+    /// Returns the codepoint if it is a surrogate, or above `U+10FFFF` - or `None` if the glyph has no codepoint
+    pub fn try_char(&self) -> Result<char, Option<u32>> {
+        let codepoint = self.codepoint.ok_or(None)?;
+        std::char::from_u32(codepoint).ok_or(Some(codepoint))
     }
 
     /// Returns the postscript name of the glyph
@@ -185,6 +1038,15 @@ impl Glyph {
         &self.name
     }
 
+    /// Returns the name of the glyph without its category prefix, such as `arrow_left` for `fa-arrow_left`  
+    /// Splits on the first `-`, the same way generated code groups glyphs into categories
+    #[must_use]
+    pub fn short_name(&self) -> &str {
+        self.name
+            .split_once('-')
+            .map_or(&self.name, |(_, rest)| rest)
+    }
+
     /// Returns the raw visual data of this glyph  
     /// Compound glyphs will be simplified to a single outline
     #[must_use]
@@ -192,10 +1054,197 @@ impl Glyph {
         &self.preview
     }
 
+    /// Returns true if the glyph is a multi-colored glyph from the font's COLR table
+    #[must_use]
+    pub fn is_color(&self) -> bool {
+        self.color_layers.is_some()
+    }
+
+    /// Returns the layers of a color glyph, bottom to top, as pairs of glyph index and color  
+    /// Colors are taken from the font's default palette - see [`Glyph::color_layers_with_palette`]  
+    /// Layers using the text foreground color are returned as opaque black
+    ///
+    /// Returns `None` if the glyph is not a color glyph
+    #[must_use]
+    pub fn color_layers(&self) -> Option<Vec<(u16, Rgba)>> {
+        self.color_layers_with_palette(0)
+    }
+
+    /// Returns the layers of a color glyph, bottom to top, using the colors from the specified palette  
+    /// Out-of-range palette indices are clamped to the last palette in the font - see [`Font::num_palettes`]
+    ///
+    /// Returns `None` if the glyph is not a color glyph
+    #[must_use]
+    pub fn color_layers_with_palette(&self, palette: usize) -> Option<Vec<(u16, Rgba)>> {
+        let layers = self.color_layers.as_ref()?;
+        let layers = layers
+            .iter()
+            .map(|(glyph_id, colors)| {
+                let color = colors.get(palette).or(colors.last()).copied();
+                (*glyph_id, color.unwrap_or(FOREGROUND))
+            })
+            .collect();
+
+        Some(layers)
+    }
+
+    /// Returns the contours of this glyph's outline  
+    /// The raw points of each contour, with their on-curve flags, are available from [`Contour::points`]  
+    /// Returns `None` if the glyph is backed by SVG data instead of a TTF outline
+    #[must_use]
+    pub fn contours(&self) -> Option<&[Contour]> {
+        match &self.preview {
+            GlyphPreview::Ttf(outline) => Some(&outline.contours),
+            GlyphPreview::Svg(_) => None,
+        }
+    }
+
+    /// Returns the components of this glyph, if it is a compound glyph built from other glyphs  
+    /// Useful for seeing how accented glyphs are constructed - [`Glyph::contours`] returns the resolved outline
+    #[must_use]
+    pub fn components(&self) -> Option<&CompoundGlyf> {
+        self.components.as_ref()
+    }
+
+    /// Returns this glyph's index, followed by the glyph indices of its color layers and components
+    fn referenced_ids(&self) -> impl Iterator<Item = u16> + '_ {
+        let layers = self.color_layers.iter().flatten().map(|(id, _)| *id);
+        let components = self
+            .components
+            .iter()
+            .flat_map(|compound| compound.components.iter().map(|c| c.glyph_id));
+        std::iter::once(self.index).chain(layers).chain(components)
+    }
+
+    /// Returns true if the glyph was originally a compound glyph, built from other glyphs  
+    /// Its outline is still resolved into a single outline - see [`Glyph::components`] for the parts
+    #[must_use]
+    pub fn is_compound(&self) -> bool {
+        self.components.is_some()
+    }
+
     /// Returns the SVG data of this glyph's outline  
     #[must_use]
     pub fn svg_preview(&self) -> String {
-        self.preview.to_svg()
+        self.preview.to_svg_with_options(&self.svg_options)
+    }
+
+    /// Returns the SVG data of this glyph's outline, filled using the specified rule  
+    /// The default, [`FillRule::NonZero`], matches how TrueType outlines are defined  
+    /// SVG-backed glyphs are returned unchanged
+    #[must_use]
+    pub fn svg_preview_with_fill_rule(&self, fill_rule: FillRule) -> String {
+        self.preview.to_svg_with_options(&SvgOptions {
+            fill_rule,
+            ..self.svg_options
+        })
+    }
+
+    /// Returns the SVG data of this glyph's outline, rendered exactly `height_px` pixels tall  
+    /// The width follows the aspect ratio of the outline, so glyphs line up when shown in a row  
+    /// SVG-backed glyphs are returned unchanged
+    #[must_use]
+    pub fn scaled_svg(&self, height_px: f32) -> String {
+        self.preview.to_svg_with_options(&SvgOptions {
+            scale_to_height: Some(height_px),
+            ..self.svg_options
+        })
+    }
+
+    /// Returns the outline of this glyph as a reusable SVG `<symbol>` with the given id, for use in a `<defs>` block  
+    /// The viewbox fits the bounds of the outline - draw it with `<use href='#id'/>`
+    ///
+    /// Glyphs backed by SVG data instead of a TTF outline produce an empty symbol
+    #[must_use]
+    pub fn to_svg_symbol(&self, id: &str) -> String {
+        let id = escape_xml(id);
+        let GlyphPreview::Ttf(outline) = &self.preview else {
+            return format!("<symbol id='{id}'></symbol>");
+        };
+
+        //
+        // Outlines are drawn with y flipped, so the top of the viewbox is at -y_max
+        let fill_rule = self.svg_options.fill_rule.as_str();
+        let viewbox = if outline.contours.is_empty() {
+            String::new()
+        } else {
+            let (x, y) = (outline.x.0, -outline.y.1);
+            let width = i32::from(outline.x.1) - i32::from(outline.x.0);
+            let height = i32::from(outline.y.1) - i32::from(outline.y.0);
+            format!(" viewBox='{x} {y} {width} {height}'")
+        };
+
+        let component = outline.as_svg_component();
+        format!("<symbol id='{id}'{viewbox} fill-rule='{fill_rule}'>{component}</symbol>")
+    }
+
+    /// Returns the options used to render this glyph's SVG previews
+    #[must_use]
+    pub fn svg_options(&self) -> SvgOptions {
+        self.svg_options
+    }
+
+    /// Sets the options used to render this glyph's SVG previews, such as the margin around the outline  
+    /// To change them for every glyph in a font, see [`Font::set_svg_options`]
+    pub fn set_svg_options(&mut self, options: SvgOptions) {
+        self.svg_options = options;
+    }
+
+    /// Renders the glyph into a `size`x`size` image, as black on a transparent background  
+    /// The outline is scaled to fit the image, and centered
+    ///
+    /// Glyphs with an SVG preview cannot be rasterized, and will produce a blank image
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    #[must_use]
+    pub fn to_dynamic_image(&self, size: u32) -> image::DynamicImage {
+        let mut image = image::RgbaImage::new(size, size);
+        if let GlyphPreview::Ttf(outline) = &self.preview {
+            let coverage = outline.rasterize(size);
+            for (pixel, alpha) in image.pixels_mut().zip(coverage) {
+                *pixel = image::Rgba([0, 0, 0, alpha]);
+            }
+        }
+
+        image::DynamicImage::ImageRgba8(image)
+    }
+
+    /// Returns the total length of the glyph's outline, in font units - useful for `stroke-dasharray` animations  
+    /// Curves are approximated with line segments. Glyphs with an SVG preview have a length of 0
+    #[must_use]
+    pub fn path_length(&self) -> f64 {
+        match &self.preview {
+            GlyphPreview::Ttf(outline) => outline.path_length(),
+            GlyphPreview::Svg(_) => 0.0,
+        }
+    }
+
+    /// Triangulates the glyph's filled outline into a mesh for GPU rendering, as `(vertices, indices)`  
+    /// Vertices are in font units with y up, and every 3 indices form a triangle
+    ///
+    /// Curves are flattened to within `tolerance` font units. Counters are cut out using the nonzero winding rule  
+    /// Glyphs with an SVG preview, or outlines that cannot be tessellated, produce an empty mesh
+    #[cfg(feature = "tessellate")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tessellate")))]
+    #[must_use]
+    pub fn tessellate(&self, tolerance: f32) -> (Vec<[f32; 2]>, Vec<u32>) {
+        match &self.preview {
+            GlyphPreview::Ttf(outline) => outline.tessellate(tolerance),
+            GlyphPreview::Svg(_) => (vec![], vec![]),
+        }
+    }
+
+    /// Computes a `size`x`size` signed distance field for the glyph, in row-major order  
+    /// Distances are in pixels, divided by `spread` and clamped to `-1.0..=1.0` - positive inside the outline, negative outside
+    ///
+    /// The outline is scaled to fit the image, less a `spread` pixel margin on each side, and centered  
+    /// Glyphs with an SVG preview cannot be rasterized, and will produce a field that is entirely outside
+    #[must_use]
+    pub fn to_sdf(&self, size: u32, spread: f32) -> Vec<f32> {
+        match &self.preview {
+            GlyphPreview::Ttf(outline) => outline.signed_distance_field(size, spread),
+            GlyphPreview::Svg(_) => vec![-1.0; (size as usize) * (size as usize)],
+        }
     }
 
     /// Returns the gzip compressed SVGZ data of this glyph
@@ -205,7 +1254,18 @@ impl Glyph {
     #[cfg(feature = "extended-svg")]
     #[cfg_attr(docsrs, doc(cfg(feature = "extended-svg")))]
     pub fn svgz_preview(&self) -> std::io::Result<Vec<u8>> {
-        self.preview.to_svgz()
+        crate::svg::compress_svg(&self.svg_preview())
+    }
+
+    /// Renders the SVG preview of this glyph into a `size`x`size` PNG image, using `resvg`  
+    /// The outline is scaled to fit the image, and centered
+    ///
+    /// # Errors
+    /// Returns an error if the SVG cannot be parsed, or the image cannot be encoded
+    #[cfg(feature = "resvg")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resvg")))]
+    pub fn render_png_via_resvg(&self, size: u32) -> std::io::Result<Vec<u8>> {
+        crate::svg::render_svg_png(&self.svg_preview(), size)
     }
 
     /// Generates a `data:image` link containing the svg data for this glyph  
@@ -215,7 +1275,7 @@ impl Glyph {
     #[cfg(feature = "extended-svg")]
     #[cfg_attr(docsrs, doc(cfg(feature = "extended-svg")))]
     pub fn svg_dataimage_url(&self) -> std::io::Result<String> {
-        self.preview.to_svg_dataimage_url()
+        crate::svg::svg_dataimage_url(&self.svg_preview())
     }
 }
 
@@ -231,13 +1291,13 @@ impl From<&Glyph> for char {
     }
 }
 
-impl From<Glyph> for u32 {
+impl From<Glyph> for Option<u32> {
     fn from(value: Glyph) -> Self {
         value.codepoint()
     }
 }
 
-impl From<&Glyph> for u32 {
+impl From<&Glyph> for Option<u32> {
     fn from(value: &Glyph) -> Self {
         value.codepoint()
     }
@@ -248,3 +1308,794 @@ impl std::fmt::Display for Glyph {
         write!(f, "{}", self.char())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::raw::ttf::test::{read_field, table_entry};
+
+    const FONT: &[u8] = include_bytes!("../../examples/slick.ttf");
+
+    #[test]
+    fn test_from_bytes() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+
+        assert_eq!(glyph.codepoint(), Some(0x2022));
+        assert_eq!(font.glyph_by_id(glyph.glyph_id()).unwrap().name(), "bullet");
+        assert_eq!(font.codepoint_map().get(&0x2022), Some(&glyph.glyph_id()));
+        assert!(glyph.svg_preview().starts_with("<svg"));
+        assert!(!glyph.contours().unwrap().is_empty());
+        assert!(!glyph.is_color());
+        assert_eq!(font.num_palettes(), 0);
+        assert_eq!(font.postscript_name(), Some("slick"));
+        assert_eq!(font.device_advance(glyph.glyph_id(), 16), None);
+    }
+
+    #[test]
+    fn test_post_metrics() {
+        let font = Font::new(FONT).unwrap();
+        assert!(font.italic_angle().abs() < f32::EPSILON);
+        assert_eq!(font.underline(), (0, 0));
+    }
+
+    #[test]
+    fn test_cmap_subtables() {
+        let font = Font::new(FONT).unwrap();
+        let subtables = font.cmap_subtables();
+        assert_eq!(subtables.len(), 3);
+        assert_eq!(
+            subtables[1],
+            CmapSubtableInfo {
+                platform: PlatformType::Macintosh,
+                encoding: 0,
+                format: 0,
+                language: 0,
+            }
+        );
+        assert_eq!(subtables[2].platform, PlatformType::Microsoft);
+        assert_eq!(subtables[2].format, 4);
+    }
+
+    #[test]
+    fn test_is_signed() {
+        let font = Font::new(FONT).unwrap();
+        assert!(!font.is_signed());
+
+        //
+        // Rename the first table in the directory, which is not parsed, to DSIG
+        let mut data = FONT.to_vec();
+        assert_eq!(&data[12..16], b"FFTM");
+        data[12..16].copy_from_slice(b"DSIG");
+        let font = Font::new(&data).unwrap();
+        assert!(font.is_signed());
+        assert!(!font.merge(&font).is_signed());
+    }
+
+    #[test]
+    fn test_parse_failures() {
+        let font = Font::new(FONT).unwrap();
+        assert!(font.parse_failures().is_empty());
+
+        //
+        // Give glyph 4 far more contours than it has data for
+        let mut data = FONT.to_vec();
+        let loca = read_field(&data, table_entry(&data, *b"loca") + 8);
+        let glyf = read_field(&data, table_entry(&data, *b"glyf") + 8);
+        let glyph_4 = glyf + 2 * u16::from_be_bytes([data[loca + 8], data[loca + 9]]) as usize;
+        data[glyph_4..glyph_4 + 2].copy_from_slice(&0x7FFFu16.to_be_bytes());
+
+        let font = Font::new(&data).unwrap();
+        assert_eq!(font.parse_failures(), [4]);
+        assert!(font.glyph_by_id(4).unwrap().contours().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_remap_codepoints() {
+        let mut font = Font::new(FONT).unwrap();
+        let bullet = font.glyph_named("bullet").unwrap().glyph_id();
+        let count = font.glyphs().len() - 1; // Every glyph but .notdef
+
+        font.remap_codepoints(0xE000).unwrap();
+        assert!(font.glyph(0x2022).is_none());
+        assert_eq!(font.glyphs()[1].codepoint(), Some(0xE000));
+        assert_eq!(font.codepoint_map().len(), count);
+
+        let glyph = font.glyph_named("bullet").unwrap();
+        let codepoint = glyph.codepoint().unwrap();
+        assert!((0xE000..0xE000 + count as u32).contains(&codepoint));
+        assert_eq!(font.codepoint_map()[&codepoint], bullet);
+        assert_eq!(font.glyph_by_id(0).unwrap().codepoint(), None);
+
+        //
+        // Surrogates are skipped
+        font.remap_codepoints(0xD7FE).unwrap();
+        assert_eq!(font.glyphs()[2].codepoint(), Some(0xD7FF));
+        assert_eq!(font.glyphs()[3].codepoint(), Some(0xE000));
+
+        //
+        // Glyphs that would run past U+10FFFF are rejected, without changing the font
+        assert_eq!(font.remap_codepoints(0x10_FFFE), Err(count - 2));
+        assert_eq!(font.remap_codepoints(u32::MAX), Err(count));
+        assert_eq!(font.glyphs()[1].codepoint(), Some(0xD7FE));
+
+        font.remap_codepoints(0x11_0000 - count as u32).unwrap();
+        assert_eq!(font.glyphs()[count].codepoint(), Some(0x10_FFFF));
+    }
+
+    #[test]
+    fn test_codepoint_hex() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+        assert_eq!(glyph.codepoint_hex().unwrap(), "U+2022");
+
+        let small = Glyph {
+            codepoint: Some(0x41),
+            ..glyph.clone()
+        };
+        assert_eq!(small.codepoint_hex().unwrap(), "U+0041");
+
+        let supplementary = Glyph {
+            codepoint: Some(0x1F600),
+            ..glyph.clone()
+        };
+        assert_eq!(supplementary.codepoint_hex().unwrap(), "U+1F600");
+        assert_eq!(font.glyph_by_id(0).unwrap().codepoint_hex(), None);
+    }
+
+    #[test]
+    fn test_try_char() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+        assert_eq!(glyph.try_char(), Ok('•'));
+
+        let invalid = Glyph {
+            codepoint: Some(0xD800),
+            ..glyph.clone()
+        };
+        assert_eq!(invalid.try_char(), Err(Some(0xD800)));
+        assert_eq!(invalid.char(), char::REPLACEMENT_CHARACTER);
+
+        let notdef = font.glyph_by_id(0).unwrap();
+        assert_eq!(notdef.try_char(), Err(None));
+        assert_eq!(notdef.char(), char::REPLACEMENT_CHARACTER);
+    }
+
+    #[test]
+    fn test_short_name() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+        assert_eq!(glyph.short_name(), "bullet");
+
+        let prefixed = Glyph {
+            name: Cow::Borrowed("fa-arrow-left"),
+            ..glyph.clone()
+        };
+        assert_eq!(prefixed.short_name(), "arrow-left");
+    }
+
+    #[test]
+    fn test_sample_text() {
+        let font = Font::new(FONT).unwrap();
+        assert_eq!(font.sample_text(), None);
+
+        //
+        // Turn the version records into sample text records
+        let mut data = FONT.to_vec();
+        let name = read_field(&data, table_entry(&data, *b"name") + 8);
+        let count = u16::from_be_bytes([data[name + 2], data[name + 3]]) as usize;
+        for record in (0..count).map(|i| name + 6 + i * 12) {
+            if data[record + 6..record + 8] == 5u16.to_be_bytes() {
+                data[record + 6..record + 8].copy_from_slice(&19u16.to_be_bytes());
+            }
+        }
+
+        let version = font.string(StringKind::NameTableVersion).unwrap();
+        let font = Font::new(&data).unwrap();
+        assert_eq!(font.sample_text(), Some(version));
+        assert_eq!(font.string(StringKind::NameTableVersion), None);
+    }
+
+    #[test]
+    fn test_all_strings() {
+        let font = Font::new(FONT).unwrap();
+        let strings = font.all_strings();
+        assert_eq!(strings.len(), font.strings().len());
+        assert!(strings.windows(2).all(|w| w[0].0 as u16 <= w[1].0 as u16));
+        assert!(strings.contains(&(StringKind::PostscriptName, "slick")));
+
+        let known = StringKind::ALL.iter().map(|kind| *kind as u16);
+        assert!(known.clone().zip(known.skip(1)).all(|(a, b)| a < b));
+    }
+
+    #[test]
+    fn test_new_with_progress() {
+        let mut calls = vec![];
+        let font = Font::new_with_progress(FONT, |done, total| calls.push((done, total))).unwrap();
+
+        let total = calls.last().unwrap().1;
+        assert!(total >= font.glyphs().len());
+        assert_eq!(calls.len(), total);
+        assert_eq!(calls.last(), Some(&(total, total)));
+    }
+
+    #[test]
+    fn test_glyphs_sorted() {
+        let font = Font::new(FONT).unwrap();
+        let glyphs = font.glyphs_sorted();
+        assert_eq!(glyphs.len(), font.glyphs().len());
+        assert!(glyphs.is_sorted_by_key(|glyph| glyph.codepoint()));
+        assert_eq!(glyphs[0].name(), ".notdef");
+    }
+
+    #[test]
+    fn test_notdef_codepoint() {
+        let font = Font::new(FONT).unwrap();
+        let notdef = font.glyph_by_id(0).unwrap();
+        assert_eq!(notdef.name(), ".notdef");
+        assert_eq!(notdef.codepoint(), None);
+        assert_eq!(notdef.unicode_range(), None);
+        assert_eq!(font.glyph_named(".notdef").unwrap().glyph_id(), 0);
+        assert!(font.glyph(0xFFFF).is_none());
+    }
+
+    #[test]
+    fn test_glyph_named_ci() {
+        let font = Font::new(FONT).unwrap();
+        let bullet = font.glyph_named("bullet").unwrap().glyph_id();
+        assert_eq!(font.glyph_named_ci("BULLET").unwrap().glyph_id(), bullet);
+        assert_eq!(font.glyph_named_ci("Bullet").unwrap().glyph_id(), bullet);
+        assert!(font.glyph_named("Bullet").is_none());
+        assert!(font.glyph_named_ci("bullets").is_none());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let font: Font = FONT.try_into().unwrap();
+        assert!(font.glyph_named("bullet").is_some());
+
+        let font = Font::try_from(FONT.to_vec()).unwrap();
+        assert!(font.glyph_named("bullet").is_some());
+        assert!(Font::try_from(vec![0u8; 4]).is_err());
+    }
+
+    #[test]
+    fn test_missing_glyf() {
+        //
+        // Replace glyf and loca with a CFF table, as in an OpenType font
+        // Until CFF outlines are supported, the OTTO header is rejected without panicking
+        let mut data = FONT.to_vec();
+        let num_tables = u16::from_be_bytes([data[4], data[5]]) as usize;
+        for entry in (0..num_tables).map(|i| 12 + i * 16) {
+            match &data[entry..entry + 4] {
+                b"glyf" => data[entry..entry + 4].copy_from_slice(b"CFF "),
+                b"loca" => data[entry..entry + 4].copy_from_slice(b"xxxx"),
+                _ => {}
+            }
+        }
+
+        data[..4].copy_from_slice(b"OTTO");
+        let err = Font::new(&data).unwrap_err();
+        assert!(matches!(err, ParseError::Unsupported { table, .. } if table == *b"OTTO"));
+
+        //
+        // A TrueType font missing its glyf table still parses, with empty outlines
+        data[..4].copy_from_slice(&0x0001_0000u32.to_be_bytes());
+        let font = Font::new(&data).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+        assert!(glyph.contours().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_timestamps() {
+        let font = Font::new(FONT).unwrap();
+        let created = font.created().unwrap();
+        let since_epoch = created.duration_since(SystemTime::UNIX_EPOCH).unwrap();
+
+        assert_eq!(since_epoch.as_secs(), 1_397_475_327);
+        assert_eq!(font.modified(), Some(created));
+    }
+
+    #[test]
+    fn test_retain_data() {
+        let font = Font::new(FONT).unwrap();
+        assert!(font.data().is_none());
+
+        let options = ParseOptions {
+            retain_data: true,
+            ..Default::default()
+        };
+        let font = Font::with_options(FONT, options).unwrap();
+        assert_eq!(font.data(), Some(FONT));
+    }
+
+    #[test]
+    fn test_strict() {
+        let options = ParseOptions {
+            strict: true,
+            ..Default::default()
+        };
+        Font::with_options(FONT, options).unwrap();
+
+        //
+        // Hide the cmap table by renaming its directory entry
+        let mut data = FONT.to_vec();
+        let pos = data.windows(4).position(|tag| tag == b"cmap").unwrap();
+        data[pos..pos + 4].copy_from_slice(b"xmap");
+
+        assert!(Font::new(&data).is_ok());
+        let err = Font::with_options(&data, options).unwrap_err();
+        assert!(err.to_string().contains("cmap"));
+    }
+
+    #[test]
+    fn test_shared_outlines() {
+        let font = Font::new(FONT).unwrap();
+
+        let outlines: HashSet<_> = font
+            .glyphs()
+            .iter()
+            .filter_map(|glyph| match glyph.outline() {
+                GlyphPreview::Ttf(outline) => Some(Arc::as_ptr(outline)),
+                GlyphPreview::Svg(_) => None,
+            })
+            .collect();
+        assert!(outlines.len() < font.glyphs().len());
+    }
+
+    #[test]
+    fn test_contour_points() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+
+        let points: Vec<Point> = glyph.contours().unwrap()[0].points().to_vec();
+        assert!(points.iter().any(|point| point.on_curve));
+        assert!(points.iter().any(|point| !point.on_curve));
+    }
+
+    #[test]
+    fn test_components() {
+        use crate::raw::ttf::{ComponentArguments, ComponentScale};
+
+        let font = Font::new(FONT).unwrap();
+        let (bullet, arrow) = (
+            font.glyph_named("bullet").unwrap(),
+            font.glyph_named("arrowleft").unwrap(),
+        );
+        assert!(bullet.components().is_none());
+        assert!(!bullet.is_compound());
+
+        //
+        // Rebuild the bullet as a compound glyph, made of the arrow
+        let mut ttf = TrueTypeFont::new(FONT).unwrap();
+        ttf.glyf_table[bullet.glyph_id() as usize] = GlyfOutline::Compound(CompoundGlyf {
+            components: vec![Component {
+                glyph_id: arrow.glyph_id(),
+                flags: 0,
+                args: ComponentArguments::ShortCoordinates(0, 0),
+                scale: ComponentScale::None,
+            }],
+        });
+
+        let font = Font::from(ttf);
+        let bullet = font.glyph_named("bullet").unwrap();
+        assert!(bullet.is_compound());
+        let components = bullet.components().unwrap();
+        assert_eq!(components.components.len(), 1);
+        assert_eq!(components.components[0].glyph_id, arrow.glyph_id());
+        assert_eq!(bullet.contours(), arrow.contours());
+    }
+
+    #[test]
+    fn test_fill_rule() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("arrowleft").unwrap();
+        assert_eq!(glyph.contours().unwrap().len(), 2);
+
+        assert!(glyph.svg_preview().contains("fill-rule='nonzero'"));
+        let svg = glyph.svg_preview_with_fill_rule(FillRule::EvenOdd);
+        assert!(svg.contains("fill-rule='evenodd'"));
+
+        //
+        // The arrow is cut out of the circle by the inner contour
+        let field = glyph.to_sdf(24, 1.0);
+        assert!(field[12 * 24 + 8] < 0.0);
+        assert!(field[12 * 24 + 18] > 0.0);
+    }
+
+    #[test]
+    fn test_svg_options() {
+        let mut font = Font::new(FONT).unwrap();
+        let default = font.glyph_named("bullet").unwrap().svg_preview();
+
+        font.set_svg_options(SvgOptions {
+            margin: None,
+            scale_to: Some(16.0),
+            ..SvgOptions::default()
+        });
+
+        let glyph = font.glyph_named("bullet").unwrap();
+        let svg = glyph.svg_preview();
+        assert_ne!(svg, default);
+        assert!(svg.contains("width='16'"));
+        assert_eq!(glyph.svg_options().margin, None);
+    }
+
+    #[test]
+    fn test_svg_stroke() {
+        let mut font = Font::new(FONT).unwrap();
+        font.set_svg_options(SvgOptions {
+            stroke: Some(SvgStroke {
+                width: 12.5,
+                color: "#00F",
+            }),
+            ..SvgOptions::default()
+        });
+
+        let svg = font.glyph_named("bullet").unwrap().svg_preview();
+        assert!(svg.contains("fill='none' stroke='#00F' stroke-width='12.5'"));
+        assert!(!svg.contains("fill-rule"));
+    }
+
+    #[test]
+    fn test_scaled_svg() {
+        let font = Font::new(FONT).unwrap();
+        for glyph in font.glyphs() {
+            if glyph.contours().is_none_or(<[_]>::is_empty) {
+                continue;
+            }
+
+            let svg = glyph.scaled_svg(24.0);
+            assert!(svg.contains("height='24'"), "{svg}");
+        }
+
+        let bullet = font.glyph_named("bullet").unwrap().scaled_svg(32.0);
+        assert!(bullet.contains("width='32' height='32'"));
+    }
+
+    #[test]
+    fn test_color_palettes() {
+        let red = Rgba {
+            r: 0xFF,
+            a: 0xFF,
+            ..Rgba::default()
+        };
+        let blue = Rgba {
+            b: 0xFF,
+            a: 0xFF,
+            ..Rgba::default()
+        };
+
+        let font = Font::new(FONT).unwrap();
+        let glyph = Glyph {
+            color_layers: Some(vec![(3, vec![red, blue])]),
+            ..font.glyph_named("bullet").unwrap().clone()
+        };
+
+        assert!(glyph.is_color());
+        assert_eq!(glyph.color_layers(), Some(vec![(3, red)]));
+        assert_eq!(glyph.color_layers_with_palette(1), Some(vec![(3, blue)]));
+        assert_eq!(glyph.color_layers_with_palette(9), Some(vec![(3, blue)]));
+    }
+
+    #[test]
+    fn test_merge() {
+        let font = Font::new(FONT).unwrap();
+        let other = Font::new(FONT).unwrap();
+
+        //
+        // Every codepoint collides, so nothing is added
+        let merged = font.merge(&other);
+        assert_eq!(merged.glyphs().len(), font.glyphs().len());
+
+        //
+        // Name collisions are suffixed
+        let glyph = font.glyph_named("bullet").unwrap();
+        let extra = Font {
+            glyphs: vec![Glyph {
+                codepoint: Some(0xE000),
+                ..glyph.clone()
+            }]
+            .into(),
+            ..other
+        };
+
+        let merged = font.merge(&extra);
+        assert_eq!(merged.glyphs().len(), font.glyphs().len() + 1);
+        assert_eq!(merged.glyph(0xE000).unwrap().name(), "bullet_2");
+        assert_eq!(merged.glyph(0x2022).unwrap().name(), "bullet");
+
+        //
+        // Glyphs from `other` are renumbered past those of `self`
+        let added = merged.codepoint_map()[&0xE000];
+        assert!(font.codepoint_map().values().all(|id| *id < added));
+        assert_eq!(merged.glyph_by_id(added).unwrap().name(), "bullet_2");
+        assert_eq!(
+            merged.glyph_by_id(glyph.glyph_id()).unwrap().name(),
+            "bullet"
+        );
+        assert_eq!(merged.codepoint_map()[&0x2022], glyph.glyph_id());
+        assert!(merged
+            .glyphs()
+            .windows(2)
+            .all(|w| w[0].glyph_id() < w[1].glyph_id()));
+    }
+
+    #[test]
+    #[cfg(all(feature = "std-fs", feature = "codegen"))]
+    fn test_export_svgs() {
+        let font = Font::new(FONT).unwrap();
+        let dir = std::env::temp_dir().join("font-map-test-export-svgs");
+
+        let count = font.export_svgs(&dir).unwrap();
+        assert_eq!(count, font.glyphs().len());
+
+        let svg = std::fs::read_to_string(dir.join("Bullet.svg")).unwrap();
+        assert_eq!(svg, font.glyph_named("bullet").unwrap().svg_preview());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "codegen")]
+    fn test_categories() {
+        let font = Font::new(FONT).unwrap();
+        let categories = font.categories();
+        assert_eq!(categories.len(), 1);
+        assert_eq!(categories["Other"], font.glyphs().len());
+    }
+
+    #[test]
+    #[cfg(feature = "codegen")]
+    fn test_to_enum_tokens() {
+        let font = Font::new(FONT).unwrap();
+        let tokens = font.to_enum_tokens("Slick").to_string();
+        let expected = crate::codegen::FontDesc::from_font("Slick", &font, false)
+            .codegen(None)
+            .to_string();
+        assert_eq!(tokens, expected);
+        assert!(tokens.contains("pub enum Slick"));
+    }
+
+    #[test]
+    fn test_svg_counters() {
+        //
+        // Each contour is its own closed sub-path, filled using the nonzero winding rule
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("arrowleft").unwrap();
+        let svg = glyph.svg_preview();
+
+        let contours = glyph.contours().unwrap().len();
+        assert_eq!(svg.matches('M').count(), contours);
+        assert_eq!(svg.matches('Z').count(), contours);
+        assert!(svg.contains("fill-rule='nonzero'"));
+    }
+
+    #[test]
+    #[cfg(feature = "resvg")]
+    fn test_svg_counters_render() {
+        use resvg::{tiny_skia, usvg};
+
+        let font = Font::new(FONT).unwrap();
+        let svg = font.glyph_named("arrowleft").unwrap().svg_preview();
+
+        let tree = usvg::Tree::from_str(&svg, &usvg::Options::default()).unwrap();
+        let mut pixmap = tiny_skia::Pixmap::new(75, 75).unwrap();
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+
+        //
+        // The arrow is a hole in the circle, and must not be filled
+        assert!(pixmap.pixel(28, 36).unwrap().red() > 128);
+        assert!(pixmap.pixel(55, 36).unwrap().red() < 128);
+    }
+
+    #[test]
+    #[cfg(feature = "resvg")]
+    fn test_render_png_via_resvg() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+
+        let png = glyph.render_png_via_resvg(32).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        assert!(glyph.render_png_via_resvg(0).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "fontdb")]
+    fn test_load_into_fontdb() {
+        let mut db = fontdb::Database::new();
+
+        let font = Font::new(FONT).unwrap();
+        assert!(font.load_into_fontdb(&mut db).is_none());
+
+        let options = ParseOptions {
+            retain_data: true,
+            ..Default::default()
+        };
+        let font = Font::with_options(FONT, options).unwrap();
+        let ids = font.load_into_fontdb(&mut db).unwrap();
+        assert_eq!(ids.len(), 1);
+        assert_eq!(db.len(), 1);
+    }
+
+    #[test]
+    fn test_uniform_advance() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+
+        assert_eq!(glyph.advance_width(), 512);
+        assert_eq!(glyph.left_side_bearing(), 37);
+        assert!(font.is_uniform_advance());
+    }
+
+    #[test]
+    fn test_variation_axes() {
+        let font = Font::new(FONT).unwrap();
+        assert!(font.variation_axes().is_empty());
+        assert!(!font.is_variable());
+
+        let record = crate::raw::ttf::VariationAxisRecord {
+            tag: *b"wght",
+            min_value: 100.0,
+            default_value: 400.0,
+            max_value: 900.0,
+            flags: 0,
+            name_id: 256,
+        };
+        let fvar = FvarTable {
+            axes: vec![
+                record,
+                crate::raw::ttf::VariationAxisRecord {
+                    tag: *b"wdth",
+                    flags: 1,
+                    ..record
+                },
+            ],
+        };
+        let avar = AvarTable {
+            segment_maps: vec![vec![(-1.0, -1.0), (0.0, 0.0), (0.5, 0.75), (1.0, 1.0)]],
+        };
+
+        let axes = VariationAxis::from_tables(&fvar, &avar);
+        assert_eq!(axes.len(), 2);
+        assert_eq!((axes[0].tag.as_str(), axes[0].hidden), ("wght", false));
+        assert_eq!(axes[0].segment_map[2], (0.5, 0.75));
+        assert_eq!((axes[1].tag.as_str(), axes[1].hidden), ("wdth", true));
+        assert_eq!(axes[1].segment_map, VariationAxis::LINEAR);
+
+        let mut ttf = TrueTypeFont::new(FONT).unwrap();
+        ttf.fvar_table = fvar;
+        assert!(Font::from(ttf).is_variable());
+    }
+
+    #[test]
+    fn test_outline_bounds() {
+        let font = Font::new(FONT).unwrap();
+        let (x_min, y_min, x_max, y_max) = font.outline_bounds();
+        assert_eq!((x_min, y_min, x_max, y_max), (37, 37, 475, 475));
+
+        for glyph in font.glyphs() {
+            if let GlyphPreview::Ttf(outline) = &glyph.preview {
+                if !outline.contours.is_empty() {
+                    assert!(x_min <= outline.x.0 && outline.x.1 <= x_max);
+                    assert!(y_min <= outline.y.0 && outline.y.1 <= y_max);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_specimen() {
+        let font = Font::new(FONT).unwrap();
+        let svg = font.render_specimen(4);
+
+        assert_eq!(svg.matches("<text").count(), font.glyphs().len());
+        assert!(svg.contains(">bullet</text>"));
+        assert_eq!(svg.matches("<path").count(), font.glyphs().len());
+
+        #[cfg(feature = "resvg")]
+        crate::svg::render_svg_png(&svg, 64).unwrap();
+    }
+
+    #[test]
+    fn test_write_svg_sprite() {
+        let font = Font::new(FONT).unwrap();
+        let mut sprite = vec![];
+        font.write_svg_sprite(&mut sprite).unwrap();
+
+        let sprite = String::from_utf8(sprite).unwrap();
+        assert!(sprite.starts_with("<svg"));
+        assert!(sprite.ends_with("</svg>"));
+        assert_eq!(sprite.matches("<symbol").count(), font.glyphs().len());
+        assert!(sprite.contains("<symbol id='bullet' viewBox='37 -475 438 438'"));
+
+        //
+        // Errors from the writer are returned
+        let mut full = [0u8; 16];
+        let err = font.write_svg_sprite(&mut &mut full[..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    fn test_to_svg_symbol() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+        let symbol = glyph.to_svg_symbol("icon-bullet");
+        assert!(symbol.starts_with("<symbol id='icon-bullet' viewBox='37 -475 438 438'"));
+        assert!(symbol.ends_with("</symbol>"));
+
+        let preview = glyph.svg_preview();
+        let component = &preview[preview.find("<path").unwrap()..preview.rfind("</svg>").unwrap()];
+        assert!(symbol.contains(component));
+
+        let symbol = glyph.to_svg_symbol("a&b");
+        assert!(symbol.starts_with("<symbol id='a&amp;b'"));
+    }
+
+    #[test]
+    fn test_svg_previews_par() {
+        let font = Font::new(FONT).unwrap();
+        let previews = font.svg_previews_par();
+        assert_eq!(previews.len(), font.glyphs().len());
+        for (preview, glyph) in previews.iter().zip(font.glyphs()) {
+            assert_eq!(*preview, glyph.svg_preview());
+        }
+    }
+
+    #[test]
+    fn test_path_length() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+
+        //
+        // The bullet is a circle 438 units across, so its outline is close to pi times that
+        let circumference = std::f64::consts::PI * 438.0;
+        assert!((glyph.path_length() - circumference).abs() / circumference < 0.01);
+    }
+
+    #[test]
+    #[cfg(feature = "tessellate")]
+    fn test_tessellate() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+        let (vertices, indices) = glyph.tessellate(0.5);
+        assert!(!vertices.is_empty());
+        assert_eq!(indices.len() % 3, 0);
+        assert!(indices.iter().all(|&i| (i as usize) < vertices.len()));
+
+        //
+        // The bullet is a circle 438 units across
+        let area: f32 = indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [0, 1, 2].map(|i| vertices[t[i] as usize]);
+                ((b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1])).abs() / 2.0
+            })
+            .sum();
+        let expected = std::f32::consts::PI * 219.0 * 219.0;
+        assert!((area - expected).abs() / expected < 0.01);
+    }
+
+    #[test]
+    fn test_to_sdf() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+
+        let field = glyph.to_sdf(32, 4.0);
+        assert_eq!(field.len(), 32 * 32);
+        assert!(field[16 * 32 + 16] > 0.0);
+        assert!((field[0] + 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_to_dynamic_image() {
+        let font = Font::new(FONT).unwrap();
+        let glyph = font.glyph_named("bullet").unwrap();
+
+        let image = glyph.to_dynamic_image(32).to_rgba8();
+        assert_eq!(image.dimensions(), (32, 32));
+        assert_eq!(image.get_pixel(16, 16)[3], 255);
+        assert_eq!(image.get_pixel(0, 0)[3], 0);
+    }
+}
@@ -0,0 +1,125 @@
+//! JSON export of the parsed font metadata
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::font::{Font, Glyph, StringKind};
+
+#[derive(Serialize)]
+struct FontJson<'a> {
+    family: Option<&'a str>,
+    postscript_name: Option<&'a str>,
+    is_monospaced: bool,
+    glyph_count: usize,
+    strings: BTreeMap<String, &'a str>,
+    glyphs: Vec<GlyphJson<'a>>,
+}
+
+#[derive(Serialize)]
+struct GlyphJson<'a> {
+    name: &'a str,
+    codepoint: Option<u32>,
+    glyph_id: u16,
+    unicode_range: Option<&'static str>,
+    advance_width: u16,
+
+    /// Each contour as a list of `[x, y, on_curve]` points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contours: Option<Vec<Vec<(i16, i16, bool)>>>,
+}
+
+impl<'a> GlyphJson<'a> {
+    fn new(glyph: &'a Glyph, include_outlines: bool) -> Self {
+        let contours = glyph
+            .contours()
+            .filter(|_| include_outlines)
+            .map(|contours| {
+                contours
+                    .iter()
+                    .map(|contour| {
+                        contour
+                            .points
+                            .iter()
+                            .map(|point| (point.x, point.y, point.on_curve))
+                            .collect()
+                    })
+                    .collect()
+            });
+
+        Self {
+            name: glyph.name(),
+            codepoint: glyph.codepoint(),
+            glyph_id: glyph.glyph_id(),
+            unicode_range: glyph.unicode_range(),
+            advance_width: glyph.advance_width(),
+            contours,
+        }
+    }
+}
+
+impl Font {
+    /// Returns a JSON document describing the font - its strings, and the name, codepoint and metrics of each glyph  
+    /// Outline data is excluded, to keep the output readable - see [`Font::to_json_with_outlines`]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        self.json(false)
+    }
+
+    /// Returns a JSON document describing the font, like [`Font::to_json`], including the contours of each glyph
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    #[must_use]
+    pub fn to_json_with_outlines(&self) -> String {
+        self.json(true)
+    }
+
+    fn json(&self, include_outlines: bool) -> String {
+        let strings = self
+            .strings()
+            .iter()
+            .map(|(kind, value)| (format!("{kind:?}"), value.as_str()))
+            .collect();
+
+        let font = FontJson {
+            family: self.string(StringKind::FontFamily),
+            postscript_name: self.postscript_name(),
+            is_monospaced: self.is_monospaced(),
+            glyph_count: self.glyphs().len(),
+            strings,
+            glyphs: self
+                .glyphs()
+                .iter()
+                .map(|glyph| GlyphJson::new(glyph, include_outlines))
+                .collect(),
+        };
+
+        serde_json::to_string_pretty(&font).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FONT: &[u8] = include_bytes!("../../examples/slick.ttf");
+
+    #[test]
+    fn test_to_json() {
+        let font = Font::new(FONT).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&font.to_json()).unwrap();
+        assert_eq!(json["postscript_name"], "slick");
+        assert_eq!(json["glyph_count"], font.glyphs().len());
+
+        let bullet = json["glyphs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|glyph| glyph["name"] == "bullet")
+            .unwrap();
+        assert_eq!(bullet["codepoint"], 0x2022);
+        assert!(bullet.get("contours").is_none());
+
+        let json: serde_json::Value = serde_json::from_str(&font.to_json_with_outlines()).unwrap();
+        assert!(json["glyphs"][0]["contours"].is_array());
+    }
+}
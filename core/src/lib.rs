@@ -23,6 +23,12 @@ pub mod codegen;
 mod svg;
 mod unicode_range;
 
+mod probe;
+pub use probe::{probe, FontKind};
+
+#[cfg(feature = "serde")]
+mod json;
+
 pub mod error;
 pub mod font;
 
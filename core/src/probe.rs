@@ -0,0 +1,68 @@
+//! Cheap detection of font formats, from their signature alone
+use crate::raw::ttf::TRUETYPE_SCALER_TYPES;
+
+/// The kind of font file detected by [`probe`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontKind {
+    /// A font with TrueType outlines - the only kind supported by [`crate::font::Font`]
+    TrueType,
+
+    /// An OpenType font with CFF outlines
+    OpenType,
+
+    /// A WOFF compressed font
+    Woff,
+
+    /// A WOFF2 compressed font
+    Woff2,
+
+    /// A collection of fonts, in a single file
+    Collection,
+
+    /// Not a recognized font format
+    Unknown,
+}
+
+impl FontKind {
+    /// Returns true if fonts of this kind can be parsed by this crate
+    #[must_use]
+    pub fn is_supported(self) -> bool {
+        self == FontKind::TrueType
+    }
+}
+
+/// Detects the kind of font in the given data, by reading only its 4-byte signature  
+/// This does not check that the rest of the data is valid - see [`crate::font::Font::new`] for that
+#[must_use]
+pub fn probe(data: &[u8]) -> FontKind {
+    let Some(signature) = data.first_chunk::<4>() else {
+        return FontKind::Unknown;
+    };
+
+    match signature {
+        b"OTTO" => FontKind::OpenType,
+        b"wOFF" => FontKind::Woff,
+        b"wOF2" => FontKind::Woff2,
+        b"ttcf" => FontKind::Collection,
+        _ if TRUETYPE_SCALER_TYPES.contains(&u32::from_be_bytes(*signature)) => FontKind::TrueType,
+        _ => FontKind::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_probe() {
+        let font = include_bytes!("../../examples/slick.ttf");
+        assert_eq!(probe(font), FontKind::TrueType);
+        assert!(probe(font).is_supported());
+
+        assert_eq!(probe(b"OTTO\0\0"), FontKind::OpenType);
+        assert_eq!(probe(b"wOF2"), FontKind::Woff2);
+        assert_eq!(probe(b"ttcf"), FontKind::Collection);
+        assert_eq!(probe(b"tt"), FontKind::Unknown);
+        assert!(!probe(b"wOFF").is_supported());
+    }
+}
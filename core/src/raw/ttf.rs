@@ -2,7 +2,7 @@
 //!
 //! The parser is designed to be fast, and minimal. Supporting only a subset of the TTF spec
 //!
-use crate::error::ParseResult;
+use crate::error::{ParseError, ParseResult};
 use crate::reader::{BinaryReader, Parse};
 
 mod post;
@@ -18,12 +18,50 @@ mod name;
 pub use name::NameKind;
 pub use name::NameTable;
 
+mod head;
+pub use head::HeadTable;
+
+mod hmtx;
+pub use hmtx::{HmtxTable, HorizontalMetric};
+
+mod colr;
+pub use colr::{BaseGlyphRecord, ColrTable, LayerRecord};
+
+mod cpal;
+pub use cpal::{CpalTable, Rgba};
+
+mod hdmx;
+pub use hdmx::{DeviceRecord, HdmxTable};
+
+mod fvar;
+pub use fvar::{FvarTable, VariationAxisRecord};
+
+mod avar;
+pub use avar::AvarTable;
+
+/// A 4-byte tag identifying a table, such as `b"cmap"`
+pub type Tag = [u8; 4];
+
+/// sfnt versions identifying a font with TrueType outlines:
+/// - `0x00010000` - The standard TrueType version
+/// - `true` - Used by older Apple TrueType fonts
+/// - `typ1` - Used by older Apple fonts wrapping a Type 1 font
+///
+/// `OTTO` fonts use CFF outlines instead, which are not supported
+pub(crate) const TRUETYPE_SCALER_TYPES: [u32; 3] = [0x0001_0000, 0x7472_7565, 0x7479_7031];
+
 /// The raw data from a TrueType font  
 /// Contains only the subset of the table needed for mapping unicode:
 /// - Codepoints
 /// - Glyph indices
 /// - Glyph names
 /// - Glyph outlines
+/// - Glyph metrics
+/// - Color glyph layers
+/// - Creation and modification timestamps
+/// - Device-specific advance widths
+/// - Variation axes
+/// - Table tags
 #[derive(Debug)]
 pub struct TrueTypeFont {
     /// The glyph outlines in the font, indexed by `glyph_id`
@@ -37,6 +75,34 @@ pub struct TrueTypeFont {
 
     /// The Name table of the font
     pub name_table: NameTable,
+
+    /// The horizontal metrics of the font
+    pub hmtx_table: HmtxTable,
+
+    /// The COLR table of the font
+    pub colr_table: ColrTable,
+
+    /// The CPAL table of the font
+    pub cpal_table: CpalTable,
+
+    /// The font header table, if the font has one
+    pub head_table: Option<HeadTable>,
+
+    /// The HDMX table of the font
+    pub hdmx_table: HdmxTable,
+
+    /// The FVAR table of the font
+    pub fvar_table: FvarTable,
+
+    /// The AVAR table of the font
+    pub avar_table: AvarTable,
+
+    /// The ids of glyphs whose outline could not be parsed, and were left empty instead  
+    /// Always empty in strict mode, where these are errors
+    pub parse_failures: Vec<u16>,
+
+    /// The tags of every table in the font's directory, including tables that are not parsed, in directory order
+    pub table_tags: Vec<Tag>,
 }
 
 impl TrueTypeFont {
@@ -47,96 +113,181 @@ impl TrueTypeFont {
     pub fn new(font_data: &[u8]) -> ParseResult<Self> {
         Self::from_data(font_data)
     }
+
+    /// Creates a new TrueType font from the given font data, in strict mode  
+    /// Unlike [`TrueTypeFont::new`], unsupported subtables, missing required tables, and undecodable strings are errors
+    ///
+    /// # Errors
+    /// Returns an error if the font data is invalid, cannot be parsed, or is not fully supported
+    pub fn new_strict(font_data: &[u8]) -> ParseResult<Self> {
+        let mut reader = BinaryReader::new(font_data);
+        reader.set_strict(true);
+        Self::parse(&mut reader)
+    }
 }
 
 fn parse_table<T: Parse>(reader: &mut BinaryReader, offset: u32, len: u32) -> ParseResult<T> {
+    let strict = reader.is_strict();
     let table = reader.read_from(offset as usize, len as usize)?;
     let mut table_reader = BinaryReader::new(table);
+    table_reader.set_strict(strict);
     T::parse(&mut table_reader)
 }
 
 impl Parse for TrueTypeFont {
     fn parse(reader: &mut BinaryReader) -> ParseResult<Self> {
+        Self::parse_with_progress(reader, None)
+    }
+}
+
+impl TrueTypeFont {
+    /// Parses the font, calling `progress` with the number of glyphs parsed so far and the total, after each glyph
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn parse_with_progress(
+        reader: &mut BinaryReader,
+        mut progress: Option<&mut dyn FnMut(usize, usize)>,
+    ) -> ParseResult<Self> {
         let mut cmap = None;
         let mut post = None;
         let mut name = None;
+        let mut colr = None;
+        let mut cpal = None;
+        let mut hdmx_table: Vec<_> = vec![];
+        let mut fvar = None;
+        let mut avar = None;
+        let mut head: Option<HeadTable> = None;
 
         //
         // Offset Table
-        reader.skip_u32()?; // Scaler type
+        let scaler_type = reader.read_u32()?;
+        if scaler_type.to_be_bytes() == *b"OTTO" {
+            return Err(ParseError::Unsupported {
+                table: *b"OTTO",
+                detail: "CFF outlines".into(),
+            });
+        } else if !TRUETYPE_SCALER_TYPES.contains(&scaler_type) {
+            return Err(ParseError::InvalidValue {
+                pos: 0,
+                value: scaler_type,
+                name: "sfnt version",
+            });
+        }
+
         let num_tables = reader.read_u16()?;
         reader.skip_u16()?; // Search range
         reader.skip_u16()?; // Entry selector
         reader.skip_u16()?; // Range shift
 
-        let mut loca_is_long = false;
         let mut glyf_offsets = vec![];
         let mut glyf_table: Vec<_> = vec![];
 
+        let mut num_h_metrics = 0;
+        let mut num_glyphs = None;
+        let mut hmtx_table: Vec<_> = vec![];
+        let mut loca_table: Vec<_> = vec![];
+
+        let mut has_glyf = false;
+        let mut seen_tags: Vec<Tag> = Vec::with_capacity(num_tables as usize);
+
         //
         // Table directory
         for _ in 0..num_tables {
-            let tag = reader.read_string(4)?;
+            let tag: Tag = reader.read_array()?;
             reader.skip_u32()?; // checksum
             let offset = reader.read_u32()?;
             let length = reader.read_u32()?;
 
-            debug_msg!("Found the {tag} table at {offset} with length {length}");
+            debug_msg!(
+                "Found the {} table at {offset} with length {length}",
+                String::from_utf8_lossy(&tag)
+            );
+
+            //
+            // A tag listed twice means a corrupt font - keep the first entry
+            if seen_tags.contains(&tag) {
+                let message = format!(
+                    "Duplicate {} table in the directory",
+                    String::from_utf8_lossy(&tag)
+                );
+                if reader.is_strict() {
+                    return Err(reader.err(&message));
+                }
 
-            match tag.as_str() {
-                "cmap" => {
+                debug_msg!("  Warning: {message}");
+                continue;
+            }
+            seen_tags.push(tag);
+
+            match &tag {
+                b"cmap" => {
                     cmap = Some(parse_table(reader, offset, length)?);
                 }
 
-                "post" => {
+                b"post" => {
                     post = Some(parse_table(reader, offset, length)?);
                 }
 
-                "name" => {
+                b"name" => {
                     name = Some(parse_table(reader, offset, length)?);
                 }
 
-                "glyf" => {
+                b"COLR" => {
+                    colr = Some(parse_table(reader, offset, length)?);
+                }
+
+                b"CPAL" => {
+                    cpal = Some(parse_table(reader, offset, length)?);
+                }
+
+                b"hdmx" => {
+                    let table = reader.read_from(offset as usize, length as usize)?;
+                    hdmx_table = table.to_vec();
+                }
+
+                b"fvar" => {
+                    fvar = Some(parse_table(reader, offset, length)?);
+                }
+
+                b"avar" => {
+                    avar = Some(parse_table(reader, offset, length)?);
+                }
+
+                b"glyf" => {
                     let table = reader.read_from(offset as usize, length as usize)?;
                     glyf_table = table.to_vec();
+                    has_glyf = true;
+                }
+
+                b"head" => {
+                    head = Some(parse_table(reader, offset, length)?);
                 }
 
-                "head" => {
+                b"hhea" => {
                     let table = reader.read_from(offset as usize, length as usize)?;
                     let mut table_reader = BinaryReader::new(table);
 
-                    table_reader.skip_u32()?; // version
-                    table_reader.skip_u32()?; // font_revision
-                    table_reader.skip_u32()?; // checksum_adjustment
-                    table_reader.skip_u32()?; // magic_number
-                    table_reader.skip_u16()?; // flags
-                    table_reader.skip_u16()?; // units_per_em
-                    table_reader.skip_u64()?; // created
-                    table_reader.skip_u64()?; // modified
-                    table_reader.skip_u64()?; // x_min-ymax
-                    table_reader.skip_u16()?; // mac_style
-                    table_reader.skip_u16()?; // lowest_rec_ppem
-                    table_reader.skip_u16()?; // font_direction_hint
-
-                    loca_is_long = table_reader.read_u16()? != 0;
-                    debug_msg!("  loca is long: {loca_is_long}");
+                    table_reader.advance_to(34)?; // Skip to number_of_h_metrics
+                    num_h_metrics = table_reader.read_u16()?;
+                    debug_msg!("  num_h_metrics: {num_h_metrics}");
                 }
 
-                "loca" => {
+                b"maxp" => {
                     let table = reader.read_from(offset as usize, length as usize)?;
                     let mut table_reader = BinaryReader::new(table);
 
-                    while !table_reader.is_eof() {
-                        let offset = if loca_is_long {
-                            table_reader.read_u32()?
-                        } else {
-                            u32::from(table_reader.read_u16()?) * 2
-                        };
+                    table_reader.advance_to(4)?; // Skip to num_glyphs
+                    num_glyphs = Some(table_reader.read_u16()?);
+                    debug_msg!("  num_glyphs: {num_glyphs:?}");
+                }
 
-                        glyf_offsets.push(offset);
-                    }
+                b"hmtx" => {
+                    let table = reader.read_from(offset as usize, length as usize)?;
+                    hmtx_table = table.to_vec();
+                }
 
-                    debug_msg!("  Found {} glyf offsets", glyf_offsets.len());
+                b"loca" => {
+                    let table = reader.read_from(offset as usize, length as usize)?;
+                    loca_table = table.to_vec();
                 }
 
                 _ => {
@@ -145,32 +296,116 @@ impl Parse for TrueTypeFont {
             }
         }
 
+        //
+        // Strict mode requires the tables we cannot do without
+        if reader.is_strict() {
+            let missing = [
+                ("cmap", cmap.is_some()),
+                ("glyf", has_glyf),
+                ("head", head.is_some()),
+            ]
+            .into_iter()
+            .find(|(_, found)| !found);
+            if let Some((tag, _)) = missing {
+                return Err(reader.err(&format!("Missing required table: {tag}")));
+            }
+        }
+
         //
         // Grab completed tables
         let cmap = cmap.unwrap_or_default();
         let post = post.unwrap_or_default();
         let name = name.unwrap_or_default();
+        let colr = colr.unwrap_or_default();
+        let cpal = cpal.unwrap_or_default();
+        let fvar = fvar.unwrap_or_default();
+        let avar = avar.unwrap_or_default();
+
+        //
+        // Parse hmtx table, now that we know how many metrics it holds
+        let mut hmtx = HmtxTable {
+            num_h_metrics,
+            ..Default::default()
+        };
+        hmtx.parse_with(&mut BinaryReader::new(&hmtx_table))?;
+
+        //
+        // Parse hdmx table, now that we know how many glyphs its records cover
+        let mut hdmx = HdmxTable {
+            num_glyphs: num_glyphs.unwrap_or(u16::MAX),
+            ..Default::default()
+        };
+        if !hdmx_table.is_empty() {
+            hdmx.parse_with(&mut BinaryReader::new(&hdmx_table))?;
+        }
+
+        //
+        // Parse loca table, now that we know the offset format from head
+        let loca_is_long = head.is_some_and(|head| head.loca_is_long);
+        let mut loca_reader = BinaryReader::new(&loca_table);
+        while !loca_reader.is_eof() {
+            let offset = if loca_is_long {
+                loca_reader.read_u32()?
+            } else {
+                u32::from(loca_reader.read_u16()?) * 2
+            };
+
+            glyf_offsets.push(offset);
+        }
+        debug_msg!("Found {} glyf offsets", glyf_offsets.len());
 
         //
         // Parse glyf table
-        let mut glyphs = vec![];
+        let total = glyf_offsets.len().saturating_sub(1);
+        let mut glyphs = Vec::with_capacity(total);
+        let mut parse_failures = vec![];
         let mut glyf_offsets = glyf_offsets.into_iter().peekable();
         while let Some(offset) = glyf_offsets.next() {
             let Some(next_offset) = glyf_offsets.peek().copied().map(|o| o as usize) else {
                 break;
             };
 
-            let length = next_offset - offset as usize;
-            let data = &glyf_table[offset as usize..next_offset];
+            #[allow(clippy::cast_possible_truncation)]
+            let glyph_id = glyphs.len() as u16;
+
+            //
+            // Offsets that go backwards, or past the end of the table, mean a malformed font - treat the glyph as empty
+            let data = glyf_table.get(offset as usize..next_offset);
+            if data.is_none() {
+                let message =
+                    format!("Invalid loca offsets {offset}..{next_offset} for glyph_id {glyph_id}");
+                if reader.is_strict() {
+                    return Err(reader.err(&message));
+                }
 
-            if length > 0 {
-                let mut glyf_reader = BinaryReader::new(data);
-                let glyph = GlyfOutline::parse(&mut glyf_reader)?;
-                glyphs.push(glyph);
-            } else {
-                debug_msg!("No outline for glyph_id {}", glyphs.len());
+                debug_msg!("  Warning: {message}");
+                parse_failures.push(glyph_id);
+            }
+
+            let data = data.unwrap_or_default();
+            if data.is_empty() {
+                debug_msg!("No outline for glyph_id {glyph_id}");
                 let glyph = GlyfOutline::default();
                 glyphs.push(glyph);
+            } else {
+                //
+                // Outlines that cannot be parsed are left empty, unless in strict mode
+                let mut glyf_reader = BinaryReader::new(data);
+                glyf_reader.set_strict(reader.is_strict());
+                match GlyfOutline::parse(&mut glyf_reader) {
+                    Ok(glyph) => glyphs.push(glyph),
+                    Err(err) if reader.is_strict() => return Err(err),
+                    #[cfg_attr(not(feature = "debug-parser"), allow(unused_variables))]
+                    Err(err) => {
+                        debug_msg!("  Warning: Failed to parse glyph_id {glyph_id}: {err}");
+                        parse_failures.push(glyph_id);
+                        glyphs.push(GlyfOutline::default());
+                    }
+                }
+            }
+
+            if let Some(progress) = progress.as_mut() {
+                progress(glyphs.len(), total);
             }
         }
 
@@ -179,12 +414,21 @@ impl Parse for TrueTypeFont {
             post_table: post,
             glyf_table: glyphs,
             name_table: name,
+            hmtx_table: hmtx,
+            colr_table: colr,
+            cpal_table: cpal,
+            head_table: head,
+            hdmx_table: hdmx,
+            fvar_table: fvar,
+            avar_table: avar,
+            parse_failures,
+            table_tags: seen_tags,
         })
     }
 }
 
 /// The platform types supported by some tables
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[repr(u16)]
 pub enum PlatformType {
     /// Unicode platform
@@ -214,3 +458,144 @@ impl From<u16> for PlatformType {
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    const FONT: &[u8] = include_bytes!("../../../examples/slick.ttf");
+
+    /// Returns the position of the table directory entry for `tag`
+    pub(crate) fn table_entry(data: &[u8], tag: Tag) -> usize {
+        (0..u16::from_be_bytes([data[4], data[5]]) as usize)
+            .map(|i| 12 + i * 16)
+            .find(|&pos| data[pos..pos + 4] == tag)
+            .unwrap()
+    }
+
+    /// Reads the 32-bit field at `pos`, such as the offset or length of a directory entry
+    pub(crate) fn read_field(data: &[u8], pos: usize) -> usize {
+        u32::from_be_bytes(data[pos..pos + 4].try_into().unwrap()) as usize
+    }
+
+    #[test]
+    fn test_scaler_types() {
+        let mut data = FONT.to_vec();
+
+        for tag in [b"true", b"typ1"] {
+            data[..4].copy_from_slice(tag);
+            TrueTypeFont::new(&data).unwrap();
+        }
+
+        data[..4].copy_from_slice(b"OTTO");
+        let err = TrueTypeFont::new(&data).unwrap_err();
+        assert!(matches!(err, ParseError::Unsupported { table, .. } if table == *b"OTTO"));
+
+        data[..4].copy_from_slice(b"wOFF");
+        let err = TrueTypeFont::new(&data).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidValue { pos: 0, .. }));
+    }
+
+    #[test]
+    fn test_loca_before_head() {
+        let mut data = FONT.to_vec();
+
+        //
+        // Rewrite loca with long offsets, appended to the end of the font
+        let (head, loca) = (table_entry(&data, *b"head"), table_entry(&data, *b"loca"));
+        let (loca_offset, loca_len) = (read_field(&data, loca + 8), read_field(&data, loca + 12));
+        let long_loca: Vec<u8> = data[loca_offset..loca_offset + loca_len]
+            .chunks_exact(2)
+            .flat_map(|word| (u32::from(u16::from_be_bytes([word[0], word[1]])) * 2).to_be_bytes())
+            .collect();
+
+        let new_offset = u32::try_from(data.len()).unwrap();
+        let new_len = u32::try_from(long_loca.len()).unwrap();
+        data.extend_from_slice(&long_loca);
+        data[loca + 8..loca + 12].copy_from_slice(&new_offset.to_be_bytes());
+        data[loca + 12..loca + 16].copy_from_slice(&new_len.to_be_bytes());
+
+        let head_offset = read_field(&data, head + 8);
+        data[head_offset + 50..head_offset + 52].copy_from_slice(&1u16.to_be_bytes());
+
+        //
+        // Move the loca entry ahead of head in the directory
+        let head_entry: [u8; 16] = data[head..head + 16].try_into().unwrap();
+        data.copy_within(loca..loca + 16, head);
+        data[loca..loca + 16].copy_from_slice(&head_entry);
+
+        let expected = TrueTypeFont::new(FONT).unwrap();
+        let font = TrueTypeFont::new(&data).unwrap();
+        assert_eq!(
+            format!("{:?}", font.glyf_table),
+            format!("{:?}", expected.glyf_table)
+        );
+    }
+
+    #[test]
+    fn test_non_monotonic_loca() {
+        let mut data = FONT.to_vec();
+
+        //
+        // Make the offset of glyph 3 larger than the offset of glyph 4
+        let loca = read_field(&data, table_entry(&data, *b"loca") + 8);
+        let glyph_4 = u16::from_be_bytes([data[loca + 8], data[loca + 9]]);
+        data[loca + 6..loca + 8].copy_from_slice(&(glyph_4 + 8).to_be_bytes());
+
+        let expected = TrueTypeFont::new(FONT).unwrap();
+        let font = TrueTypeFont::new(&data).unwrap();
+        assert_eq!(font.glyf_table.len(), expected.glyf_table.len());
+        assert_eq!(font.parse_failures, [3]);
+        assert!(
+            matches!(&font.glyf_table[3], GlyfOutline::Simple(outline) if outline.contours.is_empty())
+        );
+        assert_eq!(
+            format!("{:?}", &font.glyf_table[4..]),
+            format!("{:?}", &expected.glyf_table[4..])
+        );
+
+        let err = TrueTypeFont::new_strict(&data).unwrap_err();
+        assert!(err.to_string().contains("Invalid loca offsets"));
+    }
+
+    #[test]
+    fn test_unparseable_glyph() {
+        let mut data = FONT.to_vec();
+
+        //
+        // Give glyph 4 far more contours than it has data for
+        let loca = read_field(&data, table_entry(&data, *b"loca") + 8);
+        let glyf = read_field(&data, table_entry(&data, *b"glyf") + 8);
+        let glyph_4 = glyf + 2 * u16::from_be_bytes([data[loca + 8], data[loca + 9]]) as usize;
+        data[glyph_4..glyph_4 + 2].copy_from_slice(&0x7FFFu16.to_be_bytes());
+
+        let font = TrueTypeFont::new(&data).unwrap();
+        assert_eq!(font.parse_failures, [4]);
+        assert!(
+            matches!(&font.glyf_table[4], GlyfOutline::Simple(outline) if outline.contours.is_empty())
+        );
+
+        assert!(TrueTypeFont::new(FONT).unwrap().parse_failures.is_empty());
+        assert!(TrueTypeFont::new_strict(&data).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_tags() {
+        //
+        // Point the gasp entry at the glyf table
+        let mut data = FONT.to_vec();
+        let (gasp, glyf) = (table_entry(&data, *b"gasp"), table_entry(&data, *b"glyf"));
+        data.copy_within(glyf..glyf + 16, gasp);
+
+        let expected = TrueTypeFont::new(FONT).unwrap();
+        let font = TrueTypeFont::new(&data).unwrap();
+        assert_eq!(
+            format!("{:?}", font.glyf_table),
+            format!("{:?}", expected.glyf_table)
+        );
+
+        TrueTypeFont::new_strict(FONT).unwrap();
+        let err = TrueTypeFont::new_strict(&data).unwrap_err();
+        assert!(err.to_string().contains("Duplicate glyf table"));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::error::ParseResult;
+use crate::reader::{BinaryReader, Parse};
+
+/// The axis variations table of a TrueType font  
+/// Describes how each variation axis maps onto normalized coordinates, when the mapping is not linear
+#[derive(Debug, Default, Clone)]
+pub struct AvarTable {
+    /// The segment map of each axis, in the same order as the `fvar` axes  
+    /// Each map is a list of `(from, to)` pairs of normalized coordinates, between -1.0 and 1.0
+    pub segment_maps: Vec<Vec<(f64, f64)>>,
+}
+
+impl Parse for AvarTable {
+    fn parse(reader: &mut BinaryReader) -> ParseResult<Self> {
+        let mut table = Self::default();
+
+        //
+        // Table header
+        reader.skip_u16()?; // major_version
+        reader.skip_u16()?; // minor_version
+        reader.skip_u16()?; // reserved
+        let axis_count = reader.read_u16()?;
+
+        //
+        // Segment maps
+        for _ in 0..axis_count {
+            let position_map_count = reader.read_u16()?;
+            let mut map = Vec::with_capacity(position_map_count as usize);
+            for _ in 0..position_map_count {
+                let from = reader.read_f2dot14()?;
+                let to = reader.read_f2dot14()?;
+                map.push((from, to));
+            }
+
+            table.segment_maps.push(map);
+        }
+
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_segment_maps() {
+        let mut data = vec![];
+        data.extend_from_slice(&[0, 1, 0, 0, 0, 0]); // version, reserved
+        data.extend_from_slice(&2u16.to_be_bytes()); // axis_count
+
+        data.extend_from_slice(&4u16.to_be_bytes()); // position_map_count
+        for (from, to) in [
+            (-0x4000i16, -0x4000i16),
+            (0, 0),
+            (0x2000, 0x3000),
+            (0x4000, 0x4000),
+        ] {
+            data.extend_from_slice(&from.to_be_bytes());
+            data.extend_from_slice(&to.to_be_bytes());
+        }
+
+        data.extend_from_slice(&0u16.to_be_bytes()); // position_map_count
+
+        let table = AvarTable::from_data(&data).unwrap();
+        assert_eq!(
+            table.segment_maps,
+            vec![
+                vec![(-1.0, -1.0), (0.0, 0.0), (0.5, 0.75), (1.0, 1.0)],
+                vec![]
+            ]
+        );
+    }
+}
@@ -1,14 +1,18 @@
 #![allow(clippy::cast_possible_wrap)]
 use super::PlatformType;
-use crate::error::ParseResult;
+use crate::error::{ParseError, ParseResult};
 use crate::reader::{BinaryReader, Parse};
 
+/// The highest valid unicode codepoint
+const MAX_CODEPOINT: u32 = 0x10_FFFF;
+
 /// CMAP table data  
 /// Contains only the subset of the table needed for mapping unicode codepoints to glyph indices
 #[derive(Debug, Default)]
 pub struct CmapTable {
-    /// Mapping from glyph indices to unicode codepoints
-    pub mappings: Vec<u32>,
+    /// Mapping from glyph indices to unicode codepoints  
+    /// Glyphs without a mapping, including glyph 0, are `None`
+    pub mappings: Vec<Option<u32>>,
 
     /// Raw Subtables
     pub tables: Vec<CmapSubtable>,
@@ -18,11 +22,7 @@ impl CmapTable {
     /// Returns the unicode codepoint for the given glyph index
     #[must_use]
     pub fn get_codepoint(&self, index: u16) -> Option<u32> {
-        if index as usize >= self.mappings.len() {
-            None
-        } else {
-            Some(self.mappings[index as usize])
-        }
+        self.mappings.get(index as usize).copied().flatten()
     }
 }
 
@@ -55,12 +55,28 @@ impl Parse for CmapTable {
             subtable.platform = platform_id.into();
             subtable.encoding = encoding_id;
 
+            //
+            // Macintosh subtables map legacy character codes, which need translating to unicode
+            let is_mac = matches!(subtable.platform, PlatformType::Macintosh);
+            if is_mac {
+                subtable.translate_mac_codes();
+            }
+
             for (idx, cde) in &subtable.mappings {
+                // Codepoints mapped to glyph 0 are missing from the font, they do not belong to it
                 let idx = *idx as usize;
+                if idx == 0 {
+                    continue;
+                }
+
                 if table.mappings.len() <= idx {
-                    table.mappings.resize(idx + 1, 0xFFFF);
+                    table.mappings.resize(idx + 1, None);
+                }
+
+                // Unicode subtables take priority over Macintosh ones
+                if !is_mac || table.mappings[idx].is_none() {
+                    table.mappings[idx] = Some(*cde);
                 }
-                table.mappings[idx] = *cde;
             }
             table.tables.push(subtable);
         }
@@ -78,36 +94,77 @@ pub struct CmapSubtable {
     /// Encoding type
     pub encoding: u16,
 
+    /// Subtable format, such as 4 or 12
+    pub format: u16,
+
+    /// Language of the subtable - only meaningful for Macintosh subtables, where it identifies the script  
+    /// 0 for subtables that are language-independent, or whose format is not supported
+    pub language: u32,
+
     /// Mappings from glyph indices to unicode codepoints
     pub mappings: Vec<(u16, u32)>,
 }
 
+impl CmapSubtable {
+    /// Translates the codes of a Macintosh subtable into unicode codepoints  
+    /// Only the Roman encoding is supported - mappings in any other encoding are dropped
+    fn translate_mac_codes(&mut self) {
+        if self.encoding != 0 {
+            debug_msg!(
+                "  Skipping unsupported Macintosh encoding {}",
+                self.encoding
+            );
+            self.mappings.clear();
+            return;
+        }
+
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+        let (roman, _, _) = encoding_rs::MACINTOSH.decode(&bytes);
+        let roman: Vec<char> = roman.chars().collect();
+
+        self.mappings
+            .retain_mut(|(_, codepoint)| match roman.get(*codepoint as usize) {
+                Some(c) => {
+                    *codepoint = u32::from(*c);
+                    true
+                }
+                None => false,
+            });
+    }
+}
+
 impl Parse for CmapSubtable {
     #[allow(clippy::too_many_lines)]
     fn parse(reader: &mut BinaryReader) -> ParseResult<Self> {
+        let start = reader.pos();
         let fmt = reader.read_u16()?;
 
-        let mut subtable = Self::default();
+        let mut subtable = Self {
+            format: fmt,
+            ..Self::default()
+        };
         debug_msg!("  CMAP format: {}", fmt);
 
         match fmt {
             0 => {
                 //
                 // Format 0 CMAP tables are simple 1:1 mappings
-                reader.skip_u16()?; // length
-                reader.skip_u16()?; // language
+                let length = reader.read_u16()?;
+                subtable.language = u32::from(reader.read_u16()?);
 
                 for codepoint in 0u32..=0xFF {
                     let glyph_index = u16::from(reader.read_u8()?);
                     subtable.mappings.push((glyph_index, codepoint));
                 }
+
+                reader.check_length(start, length as usize, true)?;
             }
 
             4 => {
                 //
                 // Format 4 CMAP tables are segmented mappings
-                reader.skip_u16()?; // length
-                reader.skip_u16()?; // language
+                let length = reader.read_u16()?;
+                subtable.language = u32::from(reader.read_u16()?);
 
                 let mut seg_count = reader.read_u16()?;
                 seg_count /= 2;
@@ -136,6 +193,21 @@ impl Parse for CmapSubtable {
                 for i in 0..seg_count as usize {
                     let id_range_offset = reader.read_u16()?;
 
+                    //
+                    // A segment that ends before it starts means a corrupt font - skip it
+                    if start_code[i] > end_code[i] {
+                        let message = format!(
+                            "Inverted cmap segment {:#06X}..={:#06X}",
+                            start_code[i], end_code[i]
+                        );
+                        if reader.is_strict() {
+                            return Err(reader.err(&message));
+                        }
+
+                        debug_msg!("  Warning: {message}");
+                        continue;
+                    }
+
                     for codepoint in start_code[i]..=end_code[i] {
                         if codepoint == 0xFFFF {
                             subtable.mappings.push((0, 0xFFFF));
@@ -168,11 +240,14 @@ impl Parse for CmapSubtable {
                         subtable.mappings.push((glyph_index, u32::from(codepoint)));
                     }
                 }
+
+                // The glyph index array is read out of order, so only the segments are checked
+                reader.check_length(start, length as usize, false)?;
             }
 
             6 => {
-                reader.skip_u16()?; // len
-                reader.skip_u16()?; // lang
+                let length = reader.read_u16()?;
+                subtable.language = u32::from(reader.read_u16()?);
 
                 let first_code = reader.read_u16()?;
                 let entry_count = reader.read_u16()?;
@@ -188,14 +263,16 @@ impl Parse for CmapSubtable {
                     let codepoint = u32::from(first_code) + i;
                     subtable.mappings.push((glyph_index, codepoint));
                 }
+
+                reader.check_length(start, length as usize, true)?;
             }
 
             12 => {
                 //
                 // Format 12 CMAP tables are segmented mappings
                 reader.skip_u16()?; // reserved
-                reader.skip_u32()?; // len
-                reader.skip_u32()?; // lang
+                let length = reader.read_u32()?;
+                subtable.language = reader.read_u32()?;
                 let num_groups = reader.read_u32()?;
 
                 debug_msg!("  CMAP format 12: num_groups={}", num_groups);
@@ -212,22 +289,180 @@ impl Parse for CmapSubtable {
                         start_glyph
                     );
 
-                    let adj = if start < end { 1 } else { -1 };
+                    // Codepoints above U+10FFFF can never be valid chars
+                    if start > MAX_CODEPOINT {
+                        continue;
+                    }
 
-                    let n = start.abs_diff(end);
-                    let mut codepoint = start;
-                    for i in 0..n {
-                        let index = u16::try_from(start_glyph + i).unwrap_or_default();
+                    for codepoint in start..=end.min(MAX_CODEPOINT) {
+                        let glyph = start_glyph.wrapping_add(codepoint - start);
+                        let index = u16::try_from(glyph).unwrap_or_default();
                         subtable.mappings.push((index, codepoint));
-                        codepoint = codepoint.wrapping_add_signed(adj);
                     }
                 }
+
+                reader.check_length(start, length as usize, true)?;
+            }
+
+            _ if reader.is_strict() => {
+                return Err(ParseError::Unsupported {
+                    table: *b"cmap",
+                    detail: format!("subtable format {fmt}"),
+                });
             }
 
-            _ => return Err(reader.err(&format!("Unsupported CMAP format: {fmt}"))),
+            _ => {
+                debug_msg!("  Skipping unsupported CMAP format {fmt}");
+            }
         }
 
+        //
+        // Skip codepoints that are not valid chars, such as surrogates
+        subtable
+            .mappings
+            .retain(|(_, codepoint)| char::from_u32(*codepoint).is_some());
+
         debug_msg!("  Found {} mappings", subtable.mappings.len());
         Ok(subtable)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format12_out_of_range() {
+        let mut data = vec![];
+        data.extend_from_slice(&12u16.to_be_bytes()); // format
+        data.extend_from_slice(&0u16.to_be_bytes()); // reserved
+        data.extend_from_slice(&0u32.to_be_bytes()); // len
+        data.extend_from_slice(&0u32.to_be_bytes()); // lang
+        data.extend_from_slice(&2u32.to_be_bytes()); // num_groups
+        data.extend_from_slice(&0x10_FFFEu32.to_be_bytes()); // start
+        data.extend_from_slice(&0x11_0005u32.to_be_bytes()); // end
+        data.extend_from_slice(&1u32.to_be_bytes()); // start_glyph
+        data.extend_from_slice(&0x11_0000u32.to_be_bytes()); // start
+        data.extend_from_slice(&0x11_0002u32.to_be_bytes()); // end
+        data.extend_from_slice(&3u32.to_be_bytes()); // start_glyph
+
+        let subtable = CmapSubtable::from_data(&data).unwrap();
+        assert_eq!(subtable.mappings, [(1, 0x10_FFFE), (2, 0x10_FFFF)]);
+    }
+
+    #[test]
+    fn test_mac_roman() {
+        let mut data = vec![];
+        data.extend_from_slice(&0u16.to_be_bytes()); // version
+        data.extend_from_slice(&1u16.to_be_bytes()); // num_tables
+        data.extend_from_slice(&1u16.to_be_bytes()); // platform_id
+        data.extend_from_slice(&0u16.to_be_bytes()); // encoding_id
+        data.extend_from_slice(&12u32.to_be_bytes()); // offset
+
+        data.extend_from_slice(&0u16.to_be_bytes()); // format
+        data.extend_from_slice(&262u16.to_be_bytes()); // length
+        data.extend_from_slice(&0u16.to_be_bytes()); // language
+        let mut glyph_ids = [0u8; 256];
+        glyph_ids[b'A' as usize] = 1;
+        glyph_ids[0x80] = 2; // Ä in Mac Roman
+        data.extend_from_slice(&glyph_ids);
+
+        let table = CmapTable::from_data(&data).unwrap();
+        assert_eq!(table.get_codepoint(1), Some(u32::from('A')));
+        assert_eq!(table.get_codepoint(2), Some(u32::from('Ä')));
+    }
+
+    #[test]
+    fn test_unmapped_glyphs() {
+        let mut data = vec![];
+        data.extend_from_slice(&0u16.to_be_bytes()); // version
+        data.extend_from_slice(&1u16.to_be_bytes()); // num_tables
+        data.extend_from_slice(&0u16.to_be_bytes()); // platform_id
+        data.extend_from_slice(&3u16.to_be_bytes()); // encoding_id
+        data.extend_from_slice(&12u32.to_be_bytes()); // offset
+
+        data.extend_from_slice(&6u16.to_be_bytes()); // format
+        data.extend_from_slice(&14u16.to_be_bytes()); // length
+        data.extend_from_slice(&0u16.to_be_bytes()); // lang
+        data.extend_from_slice(&0x41u16.to_be_bytes()); // first_code
+        data.extend_from_slice(&2u16.to_be_bytes()); // entry_count
+        data.extend_from_slice(&0u16.to_be_bytes()); // glyph_index - 'A' is missing
+        data.extend_from_slice(&3u16.to_be_bytes()); // glyph_index
+
+        let table = CmapTable::from_data(&data).unwrap();
+        assert_eq!(table.get_codepoint(0), None);
+        assert_eq!(table.get_codepoint(1), None);
+        assert_eq!(table.get_codepoint(3), Some(0x42));
+        assert_eq!(table.get_codepoint(4), None);
+    }
+
+    #[test]
+    fn test_inverted_segment() {
+        let mut data = vec![];
+        data.extend_from_slice(&4u16.to_be_bytes()); // format
+        data.extend_from_slice(&40u16.to_be_bytes()); // length
+        data.extend_from_slice(&0u16.to_be_bytes()); // language
+        data.extend_from_slice(&6u16.to_be_bytes()); // seg_count * 2
+        data.extend_from_slice(&[0; 6]); // search range, entry selector, range shift
+        for end in [0x41u16, 0x41, 0xFFFF] {
+            data.extend_from_slice(&end.to_be_bytes()); // end_code
+        }
+        data.extend_from_slice(&0u16.to_be_bytes()); // reserved pad
+        for start in [0x41u16, 0x50, 0xFFFF] {
+            data.extend_from_slice(&start.to_be_bytes()); // start_code - the second segment is inverted
+        }
+        for delta in [2u16.wrapping_sub(0x41), 0, 1] {
+            data.extend_from_slice(&delta.to_be_bytes()); // id_delta
+        }
+        data.extend_from_slice(&[0; 6]); // id_range_offset
+
+        let subtable = CmapSubtable::from_data(&data).unwrap();
+        assert_eq!(subtable.mappings, vec![(2, 0x41), (0, 0xFFFF)]);
+
+        let mut reader = BinaryReader::new(&data);
+        reader.set_strict(true);
+        let err = CmapSubtable::parse(&mut reader).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Inverted cmap segment 0x0050..=0x0041"));
+    }
+
+    #[test]
+    fn test_check_length() {
+        let mut data = vec![];
+        data.extend_from_slice(&6u16.to_be_bytes()); // format
+        data.extend_from_slice(&12u16.to_be_bytes()); // length, one entry too short
+        data.extend_from_slice(&17u16.to_be_bytes()); // lang - Japanese, on the Macintosh platform
+        data.extend_from_slice(&0x41u16.to_be_bytes()); // first_code
+        data.extend_from_slice(&2u16.to_be_bytes()); // entry_count
+        data.extend_from_slice(&1u16.to_be_bytes()); // glyph_index
+        data.extend_from_slice(&2u16.to_be_bytes()); // glyph_index
+
+        let subtable = CmapSubtable::from_data(&data).unwrap();
+        assert_eq!(subtable.mappings, vec![(1, 0x41), (2, 0x42)]);
+        assert_eq!((subtable.format, subtable.language), (6, 17));
+
+        let mut reader = BinaryReader::new(&data);
+        reader.set_strict(true);
+        let err = CmapSubtable::parse(&mut reader).unwrap_err();
+        assert!(matches!(err, ParseError::Parse { pos: 14, .. }));
+
+        data[2..4].copy_from_slice(&14u16.to_be_bytes());
+        let mut reader = BinaryReader::new(&data);
+        reader.set_strict(true);
+        CmapSubtable::parse(&mut reader).unwrap();
+    }
+
+    #[test]
+    fn test_unsupported_format() {
+        let data = 2u16.to_be_bytes(); // format
+
+        let subtable = CmapSubtable::from_data(&data).unwrap();
+        assert!(subtable.mappings.is_empty());
+
+        let mut reader = BinaryReader::new(&data);
+        reader.set_strict(true);
+        let err = CmapSubtable::parse(&mut reader).unwrap_err();
+        assert!(matches!(err, ParseError::Unsupported { table, .. } if &table == b"cmap"));
+    }
+}
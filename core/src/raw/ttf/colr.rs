@@ -0,0 +1,127 @@
+use crate::error::ParseResult;
+use crate::reader::{BinaryReader, Parse};
+
+/// The COLR table of a TrueType font  
+/// Contains only the version 0 layer data, mapping color glyphs to a stack of colored layers
+#[derive(Debug, Default)]
+pub struct ColrTable {
+    /// The color glyphs in the table, sorted by glyph index
+    pub base_glyphs: Vec<BaseGlyphRecord>,
+
+    /// The layers referenced by the color glyphs
+    pub layers: Vec<LayerRecord>,
+}
+
+impl ColrTable {
+    /// Returns the layers of the glyph at the specified index, bottom to top, if it is a color glyph
+    #[must_use]
+    pub fn get_layers(&self, glyph_id: u16) -> Option<&[LayerRecord]> {
+        let index = self
+            .base_glyphs
+            .binary_search_by_key(&glyph_id, |record| record.glyph_id)
+            .ok()?;
+
+        let record = &self.base_glyphs[index];
+        let start = record.first_layer_index as usize;
+        let end = start + record.num_layers as usize;
+        self.layers.get(start..end)
+    }
+}
+
+impl Parse for ColrTable {
+    fn parse(reader: &mut BinaryReader) -> ParseResult<Self> {
+        let mut table = Self::default();
+
+        //
+        // Table header - shared by all versions
+        reader.skip_u16()?; // version
+        let num_base_glyphs = reader.read_u16()?;
+        let base_glyphs_offset = reader.read_u32()? as usize;
+        let layers_offset = reader.read_u32()? as usize;
+        let num_layers = reader.read_u16()?;
+
+        debug_msg!("  Found {num_base_glyphs} color glyphs, with {num_layers} layers");
+
+        reader.advance_to(base_glyphs_offset)?;
+        for _ in 0..num_base_glyphs {
+            let glyph_id = reader.read_u16()?;
+            let first_layer_index = reader.read_u16()?;
+            let num_layers = reader.read_u16()?;
+            table.base_glyphs.push(BaseGlyphRecord {
+                glyph_id,
+                first_layer_index,
+                num_layers,
+            });
+        }
+
+        reader.advance_to(layers_offset)?;
+        for _ in 0..num_layers {
+            let glyph_id = reader.read_u16()?;
+            let palette_index = reader.read_u16()?;
+            table.layers.push(LayerRecord {
+                glyph_id,
+                palette_index,
+            });
+        }
+
+        // Should already be sorted, but lookups depend on it
+        table.base_glyphs.sort_by_key(|record| record.glyph_id);
+
+        Ok(table)
+    }
+}
+
+/// A color glyph, made up of a range of layers
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BaseGlyphRecord {
+    /// The index of the color glyph
+    pub glyph_id: u16,
+
+    /// The index of the first layer of the glyph
+    pub first_layer_index: u16,
+
+    /// The number of layers in the glyph
+    pub num_layers: u16,
+}
+
+/// A single layer of a color glyph
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LayerRecord {
+    /// The index of the glyph providing the layer's outline
+    pub glyph_id: u16,
+
+    /// The index of the layer's color in the CPAL palette  
+    /// `0xFFFF` means the text foreground color should be used
+    pub palette_index: u16,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_layers() {
+        let mut data = vec![];
+        data.extend_from_slice(&0u16.to_be_bytes()); // version
+        data.extend_from_slice(&1u16.to_be_bytes()); // num_base_glyphs
+        data.extend_from_slice(&14u32.to_be_bytes()); // base_glyphs_offset
+        data.extend_from_slice(&20u32.to_be_bytes()); // layers_offset
+        data.extend_from_slice(&2u16.to_be_bytes()); // num_layers
+
+        data.extend_from_slice(&5u16.to_be_bytes()); // glyph_id
+        data.extend_from_slice(&0u16.to_be_bytes()); // first_layer_index
+        data.extend_from_slice(&2u16.to_be_bytes()); // num_layers
+
+        data.extend_from_slice(&6u16.to_be_bytes()); // glyph_id
+        data.extend_from_slice(&1u16.to_be_bytes()); // palette_index
+        data.extend_from_slice(&7u16.to_be_bytes()); // glyph_id
+        data.extend_from_slice(&0xFFFFu16.to_be_bytes()); // palette_index
+
+        let table = ColrTable::from_data(&data).unwrap();
+        let layers = table.get_layers(5).unwrap();
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[0].glyph_id, 6);
+        assert_eq!(layers[1].palette_index, 0xFFFF);
+        assert!(table.get_layers(6).is_none());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::error::ParseResult;
+use crate::reader::{BinaryReader, Parse};
+
+/// The CPAL table of a TrueType font  
+/// Contains the color palettes used by color glyphs - the first palette is the default
+#[derive(Debug, Default)]
+pub struct CpalTable {
+    /// The colors of each palette, by palette index
+    pub palettes: Vec<Vec<Rgba>>,
+}
+
+impl CpalTable {
+    /// Returns the color at the specified index of a palette, if it exists
+    #[must_use]
+    pub fn get_color(&self, palette: usize, index: u16) -> Option<Rgba> {
+        self.palettes.get(palette)?.get(index as usize).copied()
+    }
+}
+
+impl Parse for CpalTable {
+    fn parse(reader: &mut BinaryReader) -> ParseResult<Self> {
+        let mut table = Self::default();
+
+        //
+        // Table header
+        reader.skip_u16()?; // version
+        let num_entries = reader.read_u16()?;
+        let num_palettes = reader.read_u16()?;
+        reader.skip_u16()?; // num_color_records
+        let colors_offset = reader.read_u32()? as usize;
+
+        debug_msg!("  Found {num_palettes} palettes, with {num_entries} entries");
+
+        let mut first_indices = Vec::with_capacity(num_palettes as usize);
+        for _ in 0..num_palettes {
+            first_indices.push(reader.read_u16()? as usize);
+        }
+
+        for first_index in first_indices {
+            reader.advance_to(colors_offset + first_index * 4)?;
+
+            let mut colors = Vec::with_capacity(num_entries as usize);
+            for _ in 0..num_entries {
+                let [b, g, r, a] = reader.read_array()?;
+                colors.push(Rgba { r, g, b, a });
+            }
+
+            table.palettes.push(colors);
+        }
+
+        Ok(table)
+    }
+}
+
+/// A color, with 8 bits per channel
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgba {
+    /// Red channel
+    pub r: u8,
+
+    /// Green channel
+    pub g: u8,
+
+    /// Blue channel
+    pub b: u8,
+
+    /// Alpha channel
+    pub a: u8,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_palettes() {
+        let mut data = vec![];
+        data.extend_from_slice(&0u16.to_be_bytes()); // version
+        data.extend_from_slice(&1u16.to_be_bytes()); // num_entries
+        data.extend_from_slice(&2u16.to_be_bytes()); // num_palettes
+        data.extend_from_slice(&2u16.to_be_bytes()); // num_color_records
+        data.extend_from_slice(&16u32.to_be_bytes()); // colors_offset
+        data.extend_from_slice(&1u16.to_be_bytes()); // first palette index
+        data.extend_from_slice(&0u16.to_be_bytes()); // second palette index
+
+        data.extend_from_slice(&[0x00, 0x00, 0xFF, 0xFF]); // Red
+        data.extend_from_slice(&[0xFF, 0x00, 0x00, 0x80]); // Blue
+
+        let table = CpalTable::from_data(&data).unwrap();
+        assert_eq!(table.palettes.len(), 2);
+
+        let color = table.get_color(1, 0).unwrap();
+        assert_eq!(color.r, 0xFF);
+
+        let color = table.get_color(0, 0).unwrap();
+        assert_eq!(
+            color,
+            Rgba {
+                r: 0,
+                g: 0,
+                b: 0xFF,
+                a: 0x80
+            }
+        );
+        assert!(table.get_color(0, 1).is_none());
+        assert!(table.get_color(2, 0).is_none());
+    }
+}
@@ -0,0 +1,113 @@
+use super::Tag;
+use crate::error::ParseResult;
+use crate::reader::{BinaryReader, Parse};
+
+/// The font variations table of a TrueType font  
+/// Contains only the variation axes - named instances are not parsed
+#[derive(Debug, Default, Clone)]
+pub struct FvarTable {
+    /// The variation axes of the font, in the order used by the other variation tables
+    pub axes: Vec<VariationAxisRecord>,
+}
+
+impl Parse for FvarTable {
+    fn parse(reader: &mut BinaryReader) -> ParseResult<Self> {
+        let mut table = Self::default();
+
+        //
+        // Table header
+        reader.skip_u16()?; // major_version
+        reader.skip_u16()?; // minor_version
+        let axes_offset = reader.read_u16()?;
+        reader.skip_u16()?; // reserved
+        let axis_count = reader.read_u16()?;
+        let axis_size = reader.read_u16()?;
+
+        debug_msg!("  Found {axis_count} variation axes");
+
+        //
+        // Axis records - `axis_size` allows for fields added by later versions
+        for i in 0..axis_count as usize {
+            reader.advance_to(axes_offset as usize + i * axis_size as usize)?;
+            let tag: Tag = reader.read_array()?;
+            let min_value = fixed_to_f32(reader.read_fixed32()?);
+            let default_value = fixed_to_f32(reader.read_fixed32()?);
+            let max_value = fixed_to_f32(reader.read_fixed32()?);
+            let flags = reader.read_u16()?;
+            let name_id = reader.read_u16()?;
+
+            table.axes.push(VariationAxisRecord {
+                tag,
+                min_value,
+                default_value,
+                max_value,
+                flags,
+                name_id,
+            });
+        }
+
+        Ok(table)
+    }
+}
+
+/// A single variation axis, such as weight or width
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VariationAxisRecord {
+    /// The tag identifying the axis, such as `wght`
+    pub tag: Tag,
+
+    /// The minimum value of the axis
+    pub min_value: f32,
+
+    /// The default value of the axis
+    pub default_value: f32,
+
+    /// The maximum value of the axis
+    pub max_value: f32,
+
+    /// Axis flags - `0x0001` marks an axis that should be hidden from users
+    pub flags: u16,
+
+    /// The id of the axis name in the name table
+    pub name_id: u16,
+}
+
+/// Converts a 16.16 fixed-point number into a float
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn fixed_to_f32((int, frac): (i16, u16)) -> f32 {
+    f32::from(int) + f32::from(frac) / 65536.0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_variation_axes() {
+        let mut data = vec![];
+        data.extend_from_slice(&[0, 1, 0, 0]); // version
+        data.extend_from_slice(&16u16.to_be_bytes()); // axes_offset
+        data.extend_from_slice(&2u16.to_be_bytes()); // reserved
+        data.extend_from_slice(&1u16.to_be_bytes()); // axis_count
+        data.extend_from_slice(&20u16.to_be_bytes()); // axis_size
+        data.extend_from_slice(&[0, 0, 0, 0]); // instance_count, instance_size
+
+        data.extend_from_slice(b"wght");
+        data.extend_from_slice(&(100u32 << 16).to_be_bytes()); // min
+        data.extend_from_slice(&(400u32 << 16).to_be_bytes()); // default
+        data.extend_from_slice(&((900u32 << 16) | 0x8000).to_be_bytes()); // max
+        data.extend_from_slice(&1u16.to_be_bytes()); // flags
+        data.extend_from_slice(&256u16.to_be_bytes()); // name_id
+
+        let table = FvarTable::from_data(&data).unwrap();
+        assert_eq!(table.axes.len(), 1);
+
+        let axis = table.axes[0];
+        assert_eq!(&axis.tag, b"wght");
+        assert_eq!(
+            (axis.min_value, axis.default_value, axis.max_value),
+            (100.0, 400.0, 900.5)
+        );
+        assert_eq!((axis.flags, axis.name_id), (1, 256));
+    }
+}
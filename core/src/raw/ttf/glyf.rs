@@ -3,13 +3,17 @@ use crate::error::ParseResult;
 use crate::reader::{BinaryReader, Parse};
 
 mod simple;
-pub use simple::SimpleGlyf;
+pub use simple::{Contour, Point, SimpleGlyf};
 
 mod compound;
-pub use compound::CompoundGlyf;
+pub use compound::{Component, ComponentArguments, ComponentScale, CompoundGlyf};
 
+mod raster;
 mod svg;
 
+#[cfg(feature = "tessellate")]
+mod tessellate;
+
 /// The outline features of a glyph
 #[derive(Debug, Clone)]
 pub enum GlyfOutline {
@@ -1,6 +1,8 @@
 #![allow(clippy::cast_possible_truncation)]
 #![allow(clippy::cast_possible_wrap)]
 
+use std::collections::HashMap;
+
 use crate::error::ParseResult;
 use crate::reader::{BinaryReader, Parse};
 
@@ -27,34 +29,48 @@ impl CompoundGlyf {
     /// Converts the compound glyph to a simple glyph by resolving the components
     #[must_use]
     pub fn as_simple(&self, glyf_table: &[GlyfOutline]) -> SimpleGlyf {
+        self.as_simple_cached(glyf_table, &mut HashMap::new())
+    }
+
+    /// Converts the compound glyph to a simple glyph by resolving the components  
+    /// Nested compound glyphs are only resolved once, and stored in `cache` by glyph id for reuse
+    pub fn as_simple_cached(
+        &self,
+        glyf_table: &[GlyfOutline],
+        cache: &mut HashMap<u16, SimpleGlyf>,
+    ) -> SimpleGlyf {
         let mut contours = Vec::new();
         let (mut min_x, mut max_x) = (i16::MAX, i16::MIN);
         let (mut min_y, mut max_y) = (i16::MAX, i16::MIN);
 
         debug_msg!("Glyph has {} components", self.components.len());
         for component in &self.components {
-            let glyph = &glyf_table[component.glyph_id as usize];
-            match glyph {
-                GlyfOutline::Simple(glyph) => {
-                    let glyph = component.apply_to_glyf(glyph, &contours);
-                    contours.extend_from_slice(&glyph.contours);
-
-                    min_x = min_x.min(glyph.x.0);
-                    max_x = max_x.max(glyph.x.1);
-                    min_y = min_y.min(glyph.y.0);
-                    max_y = max_y.max(glyph.y.1);
-                }
+            let glyph = match &glyf_table[component.glyph_id as usize] {
+                GlyfOutline::Simple(glyph) => component.apply_to_glyf(glyph, &contours),
 
                 GlyfOutline::Compound(glyph) => {
-                    let glyph = glyph.as_simple(glyf_table);
-                    contours.extend_from_slice(&glyph.contours);
+                    if !cache.contains_key(&component.glyph_id) {
+                        // Placeholder, so that a component referencing itself can't recurse forever
+                        cache.insert(component.glyph_id, SimpleGlyf::default());
+                        let simple = glyph.as_simple_cached(glyf_table, cache);
+                        cache.insert(component.glyph_id, simple);
+                    }
 
-                    min_x = min_x.min(glyph.x.0);
-                    max_x = max_x.max(glyph.x.1);
-                    min_y = min_y.min(glyph.y.0);
-                    max_y = max_y.max(glyph.y.1);
+                    component.apply_to_glyf(&cache[&component.glyph_id], &contours)
                 }
+            };
+
+            //
+            // Empty outlines, such as the placeholder for a cycle, have no real bounds to merge
+            if glyph.contours.is_empty() {
+                continue;
             }
+
+            contours.extend_from_slice(&glyph.contours);
+            min_x = min_x.min(glyph.x.0);
+            max_x = max_x.max(glyph.x.1);
+            min_y = min_y.min(glyph.y.0);
+            max_y = max_y.max(glyph.y.1);
         }
 
         SimpleGlyf {
@@ -139,30 +155,56 @@ impl Parse for CompoundGlyf {
     }
 }
 
+/// How a component is positioned within its compound glyph
 #[derive(Debug, Clone)]
 pub enum ComponentArguments {
+    /// An x and y offset, in font units
     ByteCoordinates(i8, i8),
+
+    /// An x and y offset, in font units
     ShortCoordinates(i16, i16),
+
+    /// A point in the compound glyph so far, and a point in the component, to be aligned
     ByteIndex(u8, u8),
+
+    /// A point in the compound glyph so far, and a point in the component, to be aligned
     ShortIndex(u16, u16),
 }
 
+/// The scale applied to a component
 #[derive(Debug, Clone)]
 pub enum ComponentScale {
+    /// The component is not scaled
     None,
+
+    /// The component is scaled by the same amount in both directions
     Scale(f64),
+
+    /// The component is scaled separately in x and y
     XYScale(f64, f64),
+
+    /// The component is transformed by a 2x2 matrix - `(xscale, scale01, scale10, yscale)`
     TwoByTwo(f64, f64, f64, f64),
 }
 
+/// A single component of a compound glyph - a reference to another glyph, and how to transform it
 #[derive(Debug, Clone)]
 pub struct Component {
+    /// The glyph index of the glyph used by this component
     pub glyph_id: u16,
+
+    /// The raw flags of the component
     pub flags: u16,
+
+    /// How the component is positioned
     pub args: ComponentArguments,
+
+    /// How the component is scaled
     pub scale: ComponentScale,
 }
 impl Component {
+    /// Transforms a point of the component's glyph into its position in the compound glyph  
+    /// `parent` is the outline of the compound glyph so far, and `child` is the outline of the component's glyph
     #[allow(clippy::many_single_char_names)]
     pub fn apply_to_point(&self, point: &mut Point, parent: &Vec<Contour>, child: &Vec<Contour>) {
         //
@@ -257,14 +299,15 @@ impl Component {
 
         //
         // Calculate the last set of parameters
+        // The offset is only scaled twice when both columns have the same magnitude - an unscaled component keeps its offset
         let m0 = a.abs().max(b.abs());
         let n0 = c.abs().max(d.abs());
-        let m = if (a.abs() - c.abs()) <= 33.0 / 65536.0 {
+        let m = if (a.abs() - c.abs()).abs() <= 33.0 / 65536.0 {
             2.0 * m0
         } else {
             m0
         };
-        let n = if (b.abs() - d.abs()) <= 33.0 / 65536.0 {
+        let n = if (b.abs() - d.abs()).abs() <= 33.0 / 65536.0 {
             2.0 * n0
         } else {
             n0
@@ -279,6 +322,9 @@ impl Component {
         point.y = y.round() as i16;
     }
 
+    /// Transforms the component's glyph into its position in the compound glyph  
+    /// `parent` is the outline of the compound glyph so far
+    #[must_use]
     pub fn apply_to_glyf(&self, glyf: &SimpleGlyf, parent: &Vec<Contour>) -> SimpleGlyf {
         let mut new_glyf = glyf.clone();
 
@@ -308,3 +354,136 @@ impl Component {
         new_glyf
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn component(glyph_id: u16, dx: i16) -> Component {
+        Component {
+            glyph_id,
+            flags: 0,
+            args: ComponentArguments::ShortCoordinates(dx, 0),
+            scale: ComponentScale::None,
+        }
+    }
+
+    #[test]
+    fn test_nested_components() {
+        let point = |x, y| Point {
+            x,
+            y,
+            on_curve: true,
+        };
+        let square = SimpleGlyf {
+            contours: vec![Contour {
+                points: vec![point(0, 0), point(0, 10), point(10, 10), point(10, 0)],
+            }],
+            num_contours: 1,
+            x: (0, 10),
+            y: (0, 10),
+        };
+
+        let glyf_table = vec![
+            GlyfOutline::Simple(square),
+            GlyfOutline::Compound(CompoundGlyf {
+                components: vec![component(0, 0), component(0, 20)],
+            }),
+            GlyfOutline::Compound(CompoundGlyf {
+                components: vec![component(1, 0), component(1, 100)],
+            }),
+            GlyfOutline::Compound(CompoundGlyf {
+                components: vec![component(3, 0)],
+            }),
+        ];
+        let GlyfOutline::Compound(outer) = &glyf_table[2] else {
+            unreachable!()
+        };
+
+        let mut cache = HashMap::new();
+        let glyph = outer.as_simple_cached(&glyf_table, &mut cache);
+        assert_eq!(glyph.contours.len(), 4);
+        assert_eq!(glyph.x, (0, 130));
+        assert_eq!(cache[&1].contours.len(), 2);
+
+        //
+        // A glyph referencing itself resolves to nothing, rather than recursing forever
+        let GlyfOutline::Compound(cyclic) = &glyf_table[3] else {
+            unreachable!()
+        };
+        assert!(cyclic.as_simple(&glyf_table).contours.is_empty());
+    }
+
+    #[test]
+    fn test_cycle_bounds() {
+        let point = |x, y| Point {
+            x,
+            y,
+            on_curve: true,
+        };
+        let square = SimpleGlyf {
+            contours: vec![Contour {
+                points: vec![point(0, 0), point(0, 10), point(10, 10), point(10, 0)],
+            }],
+            num_contours: 1,
+            x: (0, 10),
+            y: (0, 10),
+        };
+
+        //
+        // The empty placeholder for the cycle does not widen the bounds to the origin
+        let glyf_table = vec![
+            GlyfOutline::Simple(square),
+            GlyfOutline::Compound(CompoundGlyf {
+                components: vec![component(0, 20), component(1, 0)],
+            }),
+        ];
+        let GlyfOutline::Compound(compound) = &glyf_table[1] else {
+            unreachable!()
+        };
+
+        let glyph = compound.as_simple(&glyf_table);
+        assert_eq!(glyph.x, (20, 30));
+        assert_eq!(glyph.y, (0, 10));
+    }
+
+    #[test]
+    fn test_component_offset() {
+        let point = |x, y| Point {
+            x,
+            y,
+            on_curve: true,
+        };
+        let square = SimpleGlyf {
+            contours: vec![Contour {
+                points: vec![point(0, 0), point(0, 10), point(10, 10), point(10, 0)],
+            }],
+            num_contours: 1,
+            x: (0, 10),
+            y: (0, 10),
+        };
+
+        //
+        // An unscaled component is moved by its offset exactly once on each axis
+        // The vertical offset used to be doubled, placing this square at y = 80 instead of 40
+        let glyf_table = vec![
+            GlyfOutline::Simple(square),
+            GlyfOutline::Compound(CompoundGlyf {
+                components: vec![Component {
+                    glyph_id: 0,
+                    flags: 0,
+                    args: ComponentArguments::ShortCoordinates(30, 40),
+                    scale: ComponentScale::None,
+                }],
+            }),
+        ];
+        let GlyfOutline::Compound(compound) = &glyf_table[1] else {
+            unreachable!()
+        };
+
+        let glyph = compound.as_simple(&glyf_table);
+        assert_eq!(glyph.x, (30, 40));
+        assert_eq!(glyph.y, (40, 50));
+        assert_eq!(glyph.contours[0].points[0], point(30, 40));
+    }
+}
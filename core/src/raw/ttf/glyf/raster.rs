@@ -0,0 +1,306 @@
+#![allow(clippy::cast_precision_loss)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+use super::{simple::Contour, SimpleGlyf};
+
+/// Number of sub-scanlines sampled per row of pixels, for anti-aliasing
+#[cfg(feature = "image")]
+const SUBSAMPLES: u32 = 4;
+
+/// A line segment in pixel space
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    x0: f32,
+    y0: f32,
+    x1: f32,
+    y1: f32,
+}
+
+impl Edge {
+    /// Returns the x position where the edge crosses the horizontal line at `y`, if it does, along with its winding direction  
+    /// The bottom end of the edge is excluded, so that shared vertices are only counted once
+    fn crossing(&self, y: f32) -> Option<(f32, i32)> {
+        let (top, bottom) = (self.y0.min(self.y1), self.y0.max(self.y1));
+        if y >= top && y < bottom {
+            let t = (y - self.y0) / (self.y1 - self.y0);
+            let winding = if self.y1 > self.y0 { 1 } else { -1 };
+            Some((self.x0 + t * (self.x1 - self.x0), winding))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the distance from the point to the closest point on the edge
+    fn distance_to(&self, x: f32, y: f32) -> f32 {
+        let (dx, dy) = (self.x1 - self.x0, self.y1 - self.y0);
+        let length = dx * dx + dy * dy;
+
+        let t = if length > 0.0 {
+            (((x - self.x0) * dx + (y - self.y0) * dy) / length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        (x - (self.x0 + t * dx)).hypot(y - (self.y0 + t * dy))
+    }
+}
+
+impl SimpleGlyf {
+    /// Rasterizes the glyph into a `size`x`size` coverage map, one byte per pixel, rows top to bottom
+    /// The outline is scaled to fit the image, preserving its aspect ratio, and centered
+    #[cfg(feature = "image")]
+    pub(crate) fn rasterize(&self, size: u32) -> Vec<u8> {
+        let mut coverage = vec![0; (size as usize) * (size as usize)];
+        if size == 0 {
+            return coverage;
+        }
+
+        let edges = self.edges(size, 0.0);
+
+        //
+        // Fill each row using the nonzero winding rule, as TrueType outlines are defined
+        let mut crossings = vec![];
+        let mut row = vec![0.0f32; size as usize];
+        for (y, out) in coverage.chunks_mut(size as usize).enumerate() {
+            row.fill(0.0);
+
+            for sample in 0..SUBSAMPLES {
+                let scan_y = y as f32 + (sample as f32 + 0.5) / SUBSAMPLES as f32;
+
+                crossings.clear();
+                crossings.extend(edges.iter().filter_map(|edge| edge.crossing(scan_y)));
+                crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0;
+                for pair in crossings.windows(2) {
+                    winding += pair[0].1;
+                    if winding != 0 {
+                        fill_span(&mut row, pair[0].0, pair[1].0);
+                    }
+                }
+            }
+
+            for (pixel, value) in out.iter_mut().zip(&row) {
+                let value = (value / SUBSAMPLES as f32).clamp(0.0, 1.0);
+                *pixel = (value * 255.0).round() as u8;
+            }
+        }
+
+        coverage
+    }
+
+    /// Computes a `size`x`size` signed distance field for the glyph, rows top to bottom  
+    /// Distances are measured in pixels, divided by `spread` and clamped to `-1.0..=1.0`  
+    /// Positive values are inside the outline, and negative values outside
+    ///
+    /// The outline is inset by `spread` pixels on each side, so that the field is not cut off at the edges
+    pub(crate) fn signed_distance_field(&self, size: u32, spread: f32) -> Vec<f32> {
+        let edges = self.edges(size, spread);
+        let spread = spread.max(f32::EPSILON);
+
+        let mut field = Vec::with_capacity((size as usize) * (size as usize));
+        for y in 0..size {
+            for x in 0..size {
+                let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+
+                //
+                // Nonzero winding rule - sum the crossings to the right of the pixel
+                let winding: i32 = edges
+                    .iter()
+                    .filter_map(|edge| edge.crossing(py))
+                    .filter(|(cx, _)| *cx > px)
+                    .map(|(_, winding)| winding)
+                    .sum();
+
+                let distance = edges
+                    .iter()
+                    .map(|edge| edge.distance_to(px, py))
+                    .fold(f32::INFINITY, f32::min);
+
+                let sign = if winding == 0 { -1.0 } else { 1.0 };
+                field.push((sign * distance / spread).clamp(-1.0, 1.0));
+            }
+        }
+
+        field
+    }
+
+    /// Returns the total length of the outline's contours, in font units  
+    /// Curves are approximated with line segments, and each contour includes its closing segment
+    pub(crate) fn path_length(&self) -> f64 {
+        let transform = |x: i16, y: i16| (f32::from(x), f32::from(y));
+        let mut length = 0.0;
+        for contour in &self.contours {
+            let points = contour.flatten(transform);
+            for (i, &(x0, y0)) in points.iter().enumerate() {
+                let (x1, y1) = points[(i + 1) % points.len()];
+                length += f64::from(x1 - x0).hypot(f64::from(y1 - y0));
+            }
+        }
+
+        length
+    }
+
+    /// Flattens the outline into line segments, in pixel space  
+    /// The outline is scaled to fit a `size`x`size` image, less `padding` pixels on each side, and centered
+    fn edges(&self, size: u32, padding: f32) -> Vec<Edge> {
+        let width = f32::from(self.x.1) - f32::from(self.x.0);
+        let height = f32::from(self.y.1) - f32::from(self.y.0);
+        let available = size as f32 - padding * 2.0;
+        if available <= 0.0 || width <= 0.0 || height <= 0.0 {
+            return vec![];
+        }
+
+        //
+        // Map font units onto pixels - flipping the y axis, since fonts are y-up
+        let scale = available / width.max(height);
+        let x_offset = (size as f32 - width * scale) / 2.0;
+        let y_offset = (size as f32 - height * scale) / 2.0;
+        let transform = |x: i16, y: i16| {
+            (
+                (f32::from(x) - f32::from(self.x.0)) * scale + x_offset,
+                (f32::from(self.y.1) - f32::from(y)) * scale + y_offset,
+            )
+        };
+
+        let mut edges = vec![];
+        for contour in &self.contours {
+            let points = contour.flatten(transform);
+            for (i, &(x0, y0)) in points.iter().enumerate() {
+                let (x1, y1) = points[(i + 1) % points.len()];
+                if (x0, y0) != (x1, y1) {
+                    edges.push(Edge { x0, y0, x1, y1 });
+                }
+            }
+        }
+
+        edges
+    }
+}
+
+impl Contour {
+    /// Converts the contour into a closed polyline, approximating curves with line segments
+    fn flatten(&self, transform: impl Fn(i16, i16) -> (f32, f32)) -> Vec<(f32, f32)> {
+        let points = self.normalized_points(transform);
+        if points.is_empty() {
+            return vec![];
+        }
+
+        let mut polyline = vec![(points[0].0, points[0].1)];
+        let mut i = 1;
+        while i <= points.len() {
+            let (x, y, on_curve) = points[i % points.len()];
+            if on_curve {
+                polyline.push((x, y));
+                i += 1;
+                continue;
+            }
+
+            //
+            // Quadratic bezier - subdivide based on its approximate length
+            let (x0, y0) = polyline[polyline.len() - 1];
+            let (x2, y2, _) = points[(i + 1) % points.len()];
+            let length = (x - x0).hypot(y - y0) + (x2 - x).hypot(y2 - y);
+            let steps = (length / 2.0).ceil().clamp(1.0, 32.0) as u32;
+            for step in 1..=steps {
+                let t = step as f32 / steps as f32;
+                let mt = 1.0 - t;
+                polyline.push((
+                    mt * mt * x0 + 2.0 * mt * t * x + t * t * x2,
+                    mt * mt * y0 + 2.0 * mt * t * y + t * t * y2,
+                ));
+            }
+            i += 2;
+        }
+
+        polyline
+    }
+}
+
+/// Adds the horizontal coverage of the span `[x0, x1)` to a row of pixels
+#[cfg(feature = "image")]
+fn fill_span(row: &mut [f32], x0: f32, x1: f32) {
+    let x0 = x0.max(0.0);
+    let x1 = x1.min(row.len() as f32);
+    if x1 <= x0 {
+        return;
+    }
+
+    let first = x0.floor() as usize;
+    let last = (x1.ceil() as usize).min(row.len());
+    for (px, value) in row.iter_mut().enumerate().take(last).skip(first) {
+        let px = px as f32;
+        *value += x1.min(px + 1.0) - x0.max(px);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::simple::Point;
+    use super::*;
+
+    #[test]
+    fn test_sdf_square() {
+        let point = |x, y| Point {
+            x,
+            y,
+            on_curve: true,
+        };
+        let glyph = SimpleGlyf {
+            contours: vec![Contour {
+                points: vec![point(0, 0), point(0, 100), point(100, 100), point(100, 0)],
+            }],
+            num_contours: 1,
+            x: (0, 100),
+            y: (0, 100),
+        };
+
+        //
+        // The square covers pixels 2..8, after the 2px inset
+        let field = glyph.signed_distance_field(10, 2.0);
+        let at = |x: usize, y: usize| field[y * 10 + x];
+
+        assert!((at(5, 5) - 1.0).abs() < 1e-4);
+        assert!((at(2, 5) - 0.25).abs() < 1e-4);
+        assert!((at(1, 5) + 0.25).abs() < 1e-4);
+        assert!((at(0, 0) + 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_path_length() {
+        let point = |x, y, on_curve| Point { x, y, on_curve };
+        let square = Contour {
+            points: vec![
+                point(0, 0, true),
+                point(0, 100, true),
+                point(100, 100, true),
+                point(100, 0, true),
+            ],
+        };
+
+        //
+        // A quadratic curve, about 162.32 units long, plus two straight sides
+        let arc = Contour {
+            points: vec![
+                point(0, 0, true),
+                point(100, 0, true),
+                point(100, 100, false),
+                point(0, 100, true),
+            ],
+        };
+
+        let glyph = SimpleGlyf {
+            contours: vec![square],
+            num_contours: 1,
+            x: (0, 100),
+            y: (0, 100),
+        };
+        assert!((glyph.path_length() - 400.0).abs() < 1e-6);
+
+        let glyph = SimpleGlyf {
+            contours: vec![arc],
+            ..glyph
+        };
+        assert!((glyph.path_length() - 362.32).abs() < 0.5);
+    }
+}
@@ -1,9 +1,12 @@
 #![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_possible_wrap)]
 use crate::error::ParseResult;
 use crate::reader::{BinaryReader, Parse};
 
+use super::compound::{Component, ComponentArguments, ComponentScale};
+
 /// The outline features of a simple-type glyph
-#[derive(Debug, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct SimpleGlyf {
     /// The contours of the glyph
     pub contours: Vec<Contour>,
@@ -19,6 +22,39 @@ pub struct SimpleGlyf {
     pub y: (i16, i16),
 }
 
+impl SimpleGlyf {
+    /// Combines this outline with another, offset by `dx` and `dy` font units, into a single outline  
+    /// Useful for previewing ligatures or composite icons - the bounds of the result encompass both inputs
+    #[must_use]
+    pub fn overlay(&self, other: &SimpleGlyf, dx: i16, dy: i16) -> SimpleGlyf {
+        let component = Component {
+            glyph_id: 0,
+            flags: 0,
+            args: ComponentArguments::ShortCoordinates(dx, dy),
+            scale: ComponentScale::None,
+        };
+        let other = component.apply_to_glyf(other, &self.contours);
+
+        //
+        // Empty outlines have no real bounds to merge
+        if other.contours.is_empty() {
+            return self.clone();
+        } else if self.contours.is_empty() {
+            return other;
+        }
+
+        let mut contours = self.contours.clone();
+        contours.extend_from_slice(&other.contours);
+
+        SimpleGlyf {
+            num_contours: contours.len() as i16,
+            contours,
+            x: (self.x.0.min(other.x.0), self.x.1.max(other.x.1)),
+            y: (self.y.0.min(other.y.0), self.y.1.max(other.y.1)),
+        }
+    }
+}
+
 impl Parse for SimpleGlyf {
     fn parse(_: &mut BinaryReader) -> ParseResult<Self> {
         unimplemented!("Use parse_with instead")
@@ -173,15 +209,114 @@ impl Flag {
 }
 
 /// A point in a glyph outline
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Point {
+    /// Horizontal position of the point, in font units
     pub x: i16,
+
+    /// Vertical position of the point, in font units
     pub y: i16,
+
+    /// True if the point lies on the outline, false if it is a bezier control point
     pub on_curve: bool,
 }
 
 /// A set of points making up a contour in a glyph
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Contour {
+    /// The points of the contour, in drawing order
     pub points: Vec<Point>,
 }
+impl Contour {
+    /// Returns the raw points of the contour, in drawing order - the contour is implicitly closed
+    ///
+    /// Each point is either on or off the curve:
+    /// - An on-curve point following another on-curve point is the end of a straight line
+    /// - An off-curve point is the control point of a quadratic bezier, ending at the next on-curve point
+    /// - Two off-curve points in a row imply an on-curve point midway between them
+    #[must_use]
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Returns the transformed points of the contour as `(x, y, on_curve)`, ready to be walked segment by segment  
+    /// The implied on-curve points between consecutive off-curve points are inserted, and the sequence starts on the curve
+    ///
+    /// Returns an empty list if the contour has no points on the curve
+    pub(crate) fn normalized_points(
+        &self,
+        transform: impl Fn(i16, i16) -> (f32, f32),
+    ) -> Vec<(f32, f32, bool)> {
+        //
+        // Insert the implied on-curve points between consecutive off-curve points
+        let mut points = Vec::with_capacity(self.points.len() * 2);
+        for (i, point) in self.points.iter().enumerate() {
+            let (x, y) = transform(point.x, point.y);
+            points.push((x, y, point.on_curve));
+
+            let next = &self.points[(i + 1) % self.points.len()];
+            if !point.on_curve && !next.on_curve {
+                let (nx, ny) = transform(next.x, next.y);
+                points.push((f32::midpoint(x, nx), f32::midpoint(y, ny), true));
+            }
+        }
+
+        //
+        // Start from an on-curve point
+        let Some(start) = points.iter().position(|(_, _, on_curve)| *on_curve) else {
+            return vec![];
+        };
+        points.rotate_left(start);
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn square(min: i16, max: i16) -> SimpleGlyf {
+        let point = |x, y| Point {
+            x,
+            y,
+            on_curve: true,
+        };
+        SimpleGlyf {
+            contours: vec![Contour {
+                points: vec![
+                    point(min, min),
+                    point(min, max),
+                    point(max, max),
+                    point(max, min),
+                ],
+            }],
+            num_contours: 1,
+            x: (min, max),
+            y: (min, max),
+        }
+    }
+
+    #[test]
+    fn test_overlay() {
+        let glyph = square(0, 100).overlay(&square(0, 50), 200, -20);
+        assert_eq!(glyph.num_contours, 2);
+        assert_eq!(glyph.x, (0, 250));
+        assert_eq!(glyph.y, (-20, 100));
+        assert_eq!(
+            glyph.contours[1].points[0],
+            Point {
+                x: 200,
+                y: -20,
+                on_curve: true
+            }
+        );
+
+        let empty = SimpleGlyf {
+            contours: vec![],
+            num_contours: 0,
+            x: (0, 0),
+            y: (0, 0),
+        };
+        assert_eq!(empty.overlay(&square(10, 20), 5, 5), square(15, 25));
+    }
+}
@@ -1,5 +1,7 @@
 use super::{simple::Contour, SimpleGlyf};
-use crate::svg::{wrap_svg_component, PartialSvgExt, SvgExt, SvgPathComponent, SvgProperties};
+use crate::svg::{
+    wrap_svg_component, PartialSvgExt, SvgExt, SvgOptions, SvgPathComponent, SvgProperties,
+};
 
 impl PartialSvgExt for SimpleGlyf {
     /// Generate an SVG string representation of the glyph  
@@ -15,11 +17,11 @@ impl PartialSvgExt for SimpleGlyf {
         //
         // Collect inside a shape
         let shape = contours.join("");
-        format!("<path fill-rule='evenodd' d='{shape}'/>")
+        format!("<path d='{shape}'/>")
     }
 }
 impl SvgExt for SimpleGlyf {
-    fn to_svg(&self) -> String {
+    fn to_svg_with_options(&self, options: &SvgOptions) -> String {
         //
         // Get viewbox properties
         let (xmin, xmax) = (self.x.0, self.x.1);
@@ -29,8 +31,11 @@ impl SvgExt for SimpleGlyf {
         let viewbox = SvgProperties {
             viewbox_position: (xmin.into(), ymin.into()),
             viewbox_size: (width.into(), height.into()),
-            scale_to: Some(75.0),
-            margin: Some(50.0),
+            scale_to: options.scale_to,
+            scale_to_height: options.scale_to_height,
+            margin: options.margin,
+            fill_rule: options.fill_rule,
+            stroke: options.stroke,
         };
 
         //
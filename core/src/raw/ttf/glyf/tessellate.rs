@@ -0,0 +1,110 @@
+use super::SimpleGlyf;
+use lyon_tessellation::{
+    geom::point, path::Path, BuffersBuilder, FillOptions, FillRule, FillTessellator, FillVertex,
+    VertexBuffers,
+};
+
+impl SimpleGlyf {
+    /// Triangulates the filled outline into a mesh of vertices and triangle indices, in font units with y up  
+    /// Curves are flattened to within `tolerance` font units, and overlapping contours are filled using the nonzero winding rule
+    ///
+    /// Returns an empty mesh if the outline cannot be tessellated
+    pub(crate) fn tessellate(&self, tolerance: f32) -> (Vec<[f32; 2]>, Vec<u32>) {
+        let mut builder = Path::builder();
+        for contour in &self.contours {
+            let points = contour.normalized_points(|x, y| (f32::from(x), f32::from(y)));
+            if points.is_empty() {
+                continue;
+            }
+
+            builder.begin(point(points[0].0, points[0].1));
+            let mut i = 1;
+            while i < points.len() {
+                let (x, y, on_curve) = points[i];
+                if on_curve {
+                    builder.line_to(point(x, y));
+                    i += 1;
+                    continue;
+                }
+
+                let (x2, y2, _) = points[(i + 1) % points.len()];
+                builder.quadratic_bezier_to(point(x, y), point(x2, y2));
+                i += 2;
+            }
+            builder.end(true);
+        }
+        let path = builder.build();
+
+        let options =
+            FillOptions::tolerance(tolerance.max(f32::EPSILON)).with_fill_rule(FillRule::NonZero);
+        let mut mesh: VertexBuffers<[f32; 2], u32> = VertexBuffers::new();
+        let result = FillTessellator::new().tessellate_path(
+            &path,
+            &options,
+            &mut BuffersBuilder::new(&mut mesh, |vertex: FillVertex| vertex.position().to_array()),
+        );
+
+        match result {
+            Ok(()) => (mesh.vertices, mesh.indices),
+            Err(_) => (vec![], vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::raw::ttf::glyf::{Contour, Point};
+
+    /// Sums the area of every triangle in the mesh
+    fn mesh_area((vertices, indices): &(Vec<[f32; 2]>, Vec<u32>)) -> f32 {
+        indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [0, 1, 2].map(|i| vertices[t[i] as usize]);
+                ((b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1])).abs() / 2.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_tessellate_counter() {
+        let square = |min: i16, max: i16, clockwise: bool| {
+            let mut corners = [(min, min), (min, max), (max, max), (max, min)];
+            if !clockwise {
+                corners.reverse();
+            }
+
+            Contour {
+                points: corners
+                    .into_iter()
+                    .map(|(x, y)| Point {
+                        x,
+                        y,
+                        on_curve: true,
+                    })
+                    .collect(),
+            }
+        };
+
+        //
+        // The inner square winds the other way, so it is a hole in the outer one
+        let glyph = SimpleGlyf {
+            contours: vec![square(0, 100, true), square(25, 75, false)],
+            num_contours: 2,
+            x: (0, 100),
+            y: (0, 100),
+        };
+        let mesh = glyph.tessellate(0.1);
+        assert_eq!(mesh.1.len() % 3, 0);
+        assert!((mesh_area(&mesh) - 7500.0).abs() < 1e-3);
+
+        //
+        // Winding the same way, the inner square is filled - nonzero, rather than even-odd
+        let glyph = SimpleGlyf {
+            contours: vec![square(0, 100, true), square(25, 75, true)],
+            ..glyph
+        };
+        assert!((mesh_area(&glyph.tessellate(0.1)) - 10000.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::error::ParseResult;
+use crate::reader::{BinaryReader, Parse};
+
+/// The horizontal device metrics table of a TrueType font  
+/// Contains advance widths rounded to whole pixels, for specific sizes
+#[derive(Debug, Default)]
+pub struct HdmxTable {
+    /// The number of glyphs in the font, from the `maxp` table  
+    /// This field is used to prime the parser
+    pub num_glyphs: u16,
+
+    /// The device records in the table, one for each size
+    pub records: Vec<DeviceRecord>,
+}
+
+impl HdmxTable {
+    /// Returns the advance width of the glyph at the specified index, in pixels at `ppem` pixels per em, if it exists
+    #[must_use]
+    pub fn get_advance(&self, index: u16, ppem: u8) -> Option<u8> {
+        let record = self.records.iter().find(|r| r.pixel_size == ppem)?;
+        record.widths.get(index as usize).copied()
+    }
+}
+
+impl Parse for HdmxTable {
+    fn parse(_: &mut BinaryReader) -> ParseResult<Self> {
+        unimplemented!("Use parse_with instead")
+    }
+
+    fn parse_with(&mut self, reader: &mut BinaryReader) -> ParseResult<()> {
+        //
+        // Table header
+        reader.skip_u16()?; // version
+        let num_records = reader.read_u16()?;
+        let record_size = reader.read_u32()? as usize;
+
+        debug_msg!("  Found {num_records} device records");
+
+        //
+        // Each record is padded to `record_size` - the padding is dropped, leaving one width for each glyph in the font
+        for i in 0..num_records as usize {
+            reader.advance_to(8 + i * record_size)?;
+            let pixel_size = reader.read_u8()?;
+            reader.skip_u8()?; // max_width
+
+            let mut widths = reader.read(record_size.saturating_sub(2))?.to_vec();
+            widths.truncate(self.num_glyphs as usize);
+            self.records.push(DeviceRecord { pixel_size, widths });
+        }
+
+        Ok(())
+    }
+}
+
+/// The advance widths of every glyph, at a single size
+#[derive(Debug, Default, Clone)]
+pub struct DeviceRecord {
+    /// The size these widths apply to, in pixels per em
+    pub pixel_size: u8,
+
+    /// The advance width of each glyph in pixels, by glyph index
+    pub widths: Vec<u8>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_device_advances() {
+        let mut data = vec![];
+        data.extend_from_slice(&0u16.to_be_bytes()); // version
+        data.extend_from_slice(&2u16.to_be_bytes()); // num_records
+        data.extend_from_slice(&8u32.to_be_bytes()); // record_size
+
+        data.extend_from_slice(&[12, 9, 6, 7, 9, 0, 0, 0]); // 12ppem, 3 glyphs, padded
+        data.extend_from_slice(&[16, 12, 8, 10, 12, 0, 0, 0]); // 16ppem, 3 glyphs, padded
+
+        let mut table = HdmxTable {
+            num_glyphs: 3,
+            ..Default::default()
+        };
+        table.parse_with(&mut BinaryReader::new(&data)).unwrap();
+        assert_eq!(table.records.len(), 2);
+        assert_eq!(table.records[0].widths, [6, 7, 9]);
+
+        assert_eq!(table.get_advance(0, 12), Some(6));
+        assert_eq!(table.get_advance(2, 16), Some(12));
+        assert_eq!(table.get_advance(2, 13), None);
+        assert_eq!(table.get_advance(3, 12), None);
+        assert_eq!(table.get_advance(10, 12), None);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::error::ParseResult;
+use crate::reader::{BinaryReader, Parse};
+
+/// The font header table of a TrueType font  
+/// Contains only the subset of the table used by this crate
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HeadTable {
+    /// When the font was created, in seconds since 1904-01-01 00:00 UTC
+    pub created: i64,
+
+    /// When the font was last modified, in seconds since 1904-01-01 00:00 UTC
+    pub modified: i64,
+
+    /// True if the loca table uses 32-bit offsets, instead of 16-bit
+    pub loca_is_long: bool,
+}
+
+impl Parse for HeadTable {
+    fn parse(reader: &mut BinaryReader) -> ParseResult<Self> {
+        reader.skip_u32()?; // version
+        reader.skip_u32()?; // font_revision
+        reader.skip_u32()?; // checksum_adjustment
+        reader.skip_u32()?; // magic_number
+        reader.skip_u16()?; // flags
+        reader.skip_u16()?; // units_per_em
+
+        let created = reader.read_i64()?;
+        let modified = reader.read_i64()?;
+
+        reader.skip_u64()?; // x_min-ymax
+        reader.skip_u16()?; // mac_style
+        reader.skip_u16()?; // lowest_rec_ppem
+        reader.skip_u16()?; // font_direction_hint
+
+        let loca_is_long = reader.read_u16()? != 0;
+        debug_msg!("  loca is long: {loca_is_long}");
+
+        Ok(Self {
+            created,
+            modified,
+            loca_is_long,
+        })
+    }
+}
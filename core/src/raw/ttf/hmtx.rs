@@ -0,0 +1,64 @@
+use crate::error::ParseResult;
+use crate::reader::{BinaryReader, Parse};
+
+/// The horizontal metrics table of a TrueType font
+#[derive(Debug, Default)]
+pub struct HmtxTable {
+    /// The number of full metric records in the table, from the `hhea` table  
+    /// This field is used to prime the parser
+    pub num_h_metrics: u16,
+
+    /// The horizontal metrics of each glyph, by glyph index
+    pub metrics: Vec<HorizontalMetric>,
+}
+
+impl HmtxTable {
+    /// Returns the horizontal metrics of the glyph at the specified index, if they exist
+    #[must_use]
+    pub fn get_metric(&self, index: u16) -> Option<&HorizontalMetric> {
+        self.metrics.get(index as usize)
+    }
+}
+
+impl Parse for HmtxTable {
+    fn parse(_: &mut BinaryReader) -> ParseResult<Self> {
+        unimplemented!("Use parse_with instead")
+    }
+
+    fn parse_with(&mut self, reader: &mut BinaryReader) -> ParseResult<()> {
+        //
+        // Full records, containing both the advance and the bearing
+        for _ in 0..self.num_h_metrics {
+            let advance_width = reader.read_u16()?;
+            let left_side_bearing = reader.read_i16()?;
+            self.metrics.push(HorizontalMetric {
+                advance_width,
+                left_side_bearing,
+            });
+        }
+
+        //
+        // Any remaining glyphs share the last advance, and only store a bearing
+        let advance_width = self.metrics.last().map_or(0, |m| m.advance_width);
+        while reader.len() - reader.pos() >= 2 {
+            let left_side_bearing = reader.read_i16()?;
+            self.metrics.push(HorizontalMetric {
+                advance_width,
+                left_side_bearing,
+            });
+        }
+
+        debug_msg!("  Found {} horizontal metrics", self.metrics.len());
+        Ok(())
+    }
+}
+
+/// The horizontal metrics of a single glyph
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HorizontalMetric {
+    /// The advance width of the glyph, in font units
+    pub advance_width: u16,
+
+    /// The left side bearing of the glyph, in font units
+    pub left_side_bearing: i16,
+}
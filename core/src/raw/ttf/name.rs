@@ -49,7 +49,16 @@ impl Parse for NameTable {
             let mut name_reader = reader.clone();
             name_reader.advance_to(string_offset as usize + offset as usize)?;
             let name = name_reader.read(length as usize)?;
-            let name = name.decode(platform_id, encoding_id);
+            let name = if reader.is_strict() {
+                name.decode_strict(platform_id, encoding_id)
+                    .ok_or_else(|| {
+                        reader.err(&format!(
+                            "Unsupported or invalid name record: {platform_id:?}::{encoding_id}"
+                        ))
+                    })?
+            } else {
+                name.decode(platform_id, encoding_id)
+            };
 
             debug_msg!(
                 "  Name record: {platform_id:?}::{encoding_id}::{language_id}::{name_id:?} = {name}"
@@ -104,6 +113,36 @@ pub enum NameKind {
 
     Other = 0xFFFF,
 }
+impl NameKind {
+    /// All the known string kinds, in order - excludes [`NameKind::Other`]
+    pub const ALL: [Self; 25] = [
+        Self::CopyrightNotice,
+        Self::FontFamily,
+        Self::FontSubfamily,
+        Self::UniqueIdentifier,
+        Self::FullFontName,
+        Self::NameTableVersion,
+        Self::PostscriptName,
+        Self::Trademark,
+        Self::Manufacturer,
+        Self::Designer,
+        Self::Description,
+        Self::VendorUrl,
+        Self::DesignerUrl,
+        Self::LicenseDescription,
+        Self::LicenseInfoUrl,
+        Self::PreferredFamily,
+        Self::PreferredSubfamily,
+        Self::CompatibleFull,
+        Self::SampleText,
+        Self::PostscriptCid,
+        Self::WwsFamily,
+        Self::WwsSubfamily,
+        Self::LightBackgroundPalette,
+        Self::DarkBackgroundPalette,
+        Self::VariationsPostscriptNamePrefix,
+    ];
+}
 impl From<u16> for NameKind {
     fn from(value: u16) -> Self {
         match value {
@@ -141,13 +180,20 @@ impl From<u16> for NameKind {
 pub trait StringDecoderExt {
     /// Decode a string from a byte array
     fn decode(&self, platform_id: PlatformType, encoding_id: u16) -> String;
+
+    /// Decode a string from a byte array  
+    /// Returns `None` if the encoding is not supported, or the data is not valid for it
+    fn decode_strict(&self, platform_id: PlatformType, encoding_id: u16) -> Option<String>;
 }
 impl StringDecoderExt for [u8] {
     fn decode(&self, platform_id: PlatformType, encoding_id: u16) -> String {
         match (platform_id, encoding_id) {
             //
             // These are UTF-16 encoded strings
-            (PlatformType::Unicode, _) | (PlatformType::Microsoft, 1 | 10) => {
+            // Unlike in the cmap table, Microsoft encoding 10 (full unicode) is not UCS-4 here - it is also UTF-16,
+            // with characters outside the BMP stored as surrogate pairs
+            // Microsoft encoding 0 (symbol) fonts also store their strings as UTF-16
+            (PlatformType::Unicode, _) | (PlatformType::Microsoft, 0 | 1 | 10) => {
                 let words = self
                     .chunks_exact(2)
                     .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]));
@@ -162,4 +208,68 @@ impl StringDecoderExt for [u8] {
             _ => format!("UNSUPPORTED_STRING::{platform_id:?}::{encoding_id}"),
         }
     }
+
+    fn decode_strict(&self, platform_id: PlatformType, encoding_id: u16) -> Option<String> {
+        match (platform_id, encoding_id) {
+            (PlatformType::Unicode, _) | (PlatformType::Microsoft, 0 | 1 | 10) => {
+                if !self.len().is_multiple_of(2) {
+                    return None;
+                }
+
+                let words = self
+                    .chunks_exact(2)
+                    .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]));
+                String::from_utf16(&words.collect::<Vec<u16>>()).ok()
+            }
+
+            (PlatformType::Macintosh, 0) => {
+                let (s, _, had_errors) = encoding_rs::MACINTOSH.decode(self);
+                (!had_errors).then(|| s.to_string())
+            }
+
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Encodes a string as UTF-16BE, the way the name table stores it
+    fn utf16be(s: &str) -> Vec<u8> {
+        s.encode_utf16().flat_map(u16::to_be_bytes).collect()
+    }
+
+    #[test]
+    fn test_decode_supplementary_plane() {
+        let data = utf16be("Icons \u{1F600}\u{1F680}");
+        assert_eq!(data.len(), 2 * 10);
+
+        for encoding_id in [1, 10] {
+            let name = data.decode(PlatformType::Microsoft, encoding_id);
+            assert_eq!(name, "Icons \u{1F600}\u{1F680}");
+
+            let name = data.decode_strict(PlatformType::Microsoft, encoding_id);
+            assert_eq!(name.as_deref(), Some("Icons \u{1F600}\u{1F680}"));
+        }
+
+        //
+        // An unpaired high surrogate is replaced, or rejected in strict mode
+        let data = [0xD8, 0x3D, 0x00, 0x41];
+        let name = data.decode(PlatformType::Microsoft, 10);
+        assert_eq!(name, "\u{FFFD}A");
+        assert!(data.decode_strict(PlatformType::Microsoft, 10).is_none());
+    }
+
+    #[test]
+    fn test_decode_symbol() {
+        let data = utf16be("Symbol Icons");
+
+        let name = data.decode(PlatformType::Microsoft, 0);
+        assert_eq!(name, "Symbol Icons");
+
+        let name = data.decode_strict(PlatformType::Microsoft, 0);
+        assert_eq!(name.as_deref(), Some("Symbol Icons"));
+    }
 }
@@ -1,12 +1,24 @@
 #![allow(clippy::cast_possible_wrap)]
 #![allow(clippy::cast_possible_truncation)]
-use crate::error::ParseResult;
+use super::fvar::fixed_to_f32;
+use crate::error::{ParseError, ParseResult};
 use crate::reader::{BinaryReader, Parse};
 
 /// The Post table of a TrueType font  
 /// Contains only the subset of the table needed for mapping glyph indices to glyph names
 #[derive(Debug, Default)]
 pub struct PostTable {
+    /// The angle of the font's italic slant, in counter-clockwise degrees from vertical  
+    /// 0 for upright fonts, and negative for fonts leaning to the right
+    pub italic_angle: f32,
+
+    /// The distance from the baseline to the top of the underline, in font units  
+    /// Negative values are below the baseline
+    pub underline_position: i16,
+
+    /// The thickness of the underline, in font units
+    pub underline_thickness: i16,
+
     /// True if the font is monospaced
     pub is_monospaced: bool,
 
@@ -29,9 +41,9 @@ impl Parse for PostTable {
         //
         // Table header
         let fmt = reader.read_fixed32()?;
-        reader.skip_u32()?; // italic angle
-        reader.skip_u16()?; // underline position
-        reader.skip_u16()?; // underline thickness
+        table.italic_angle = fixed_to_f32(reader.read_fixed32()?);
+        table.underline_position = reader.read_i16()?;
+        table.underline_thickness = reader.read_i16()?;
         table.is_monospaced = reader.read_u32()? != 0; // is fixed pitch
         reader.skip_u32()?; // min memory t42
         reader.skip_u32()?; // max memory t42
@@ -59,12 +71,11 @@ impl Parse for PostTable {
                 let mut name_reader = reader.clone();
                 name_reader.advance_by(num_glyphs as isize * 2)?;
                 while !name_reader.is_eof() {
-                    let len = name_reader.read_u8()?;
-                    let name = name_reader.read_string(len as usize)?;
-                    names.push(name);
+                    names.push(name_reader.read_pascal_string()?);
                 }
 
                 for _ in 0..num_glyphs {
+                    let pos = reader.pos();
                     let ordinal = reader.read_u16()?;
                     if ordinal < POST_MAC_NAMES_LEN as u16 {
                         table
@@ -72,7 +83,12 @@ impl Parse for PostTable {
                             .push(POST_MAC_NAMES[ordinal as usize].to_string());
                     } else {
                         let index = (ordinal - POST_MAC_NAMES_LEN as u16) as usize;
-                        table.glyph_names.push(names[index].clone());
+                        let name = names.get(index).ok_or(ParseError::InvalidValue {
+                            pos,
+                            value: u32::from(ordinal),
+                            name: "post glyph name index",
+                        })?;
+                        table.glyph_names.push(name.clone());
                     }
                 }
             }
@@ -82,14 +98,33 @@ impl Parse for PostTable {
                 // Format 2.5 uses an 8-bit offset to the std glyph names
                 let num_glyphs = reader.read_u16()?;
 
-                let mut glyph_names = Vec::new();
                 for i in 0..num_glyphs {
+                    let pos = reader.pos();
                     let offset = reader.read_i8()?;
                     let index = i.wrapping_add_signed(i16::from(offset));
-                    glyph_names.push(POST_MAC_NAMES[index as usize].to_string());
+                    let name =
+                        POST_MAC_NAMES
+                            .get(index as usize)
+                            .ok_or(ParseError::InvalidValue {
+                                pos,
+                                value: u32::from(index),
+                                name: "post glyph name offset",
+                            })?;
+                    table.glyph_names.push((*name).to_string());
                 }
             }
 
+            (3, 0) => {
+                // Format 3.0 has no glyph names
+            }
+
+            _ if reader.is_strict() => {
+                return Err(ParseError::Unsupported {
+                    table: *b"post",
+                    detail: format!("format {}.{}", fmt.0, fmt.1),
+                });
+            }
+
             _ => {
                 // Other formats are not useful to us here
             }
@@ -130,3 +165,26 @@ const POST_MAC_NAMES: [&str; POST_MAC_NAMES_LEN] = [
     "threequarters", "franc", "Gbreve", "gbreve", "Idotaccent", "Scedilla", "scedilla", "Cacute", "cacute", "Ccaron", 
     "ccaron", "dcroat"
 ];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_header_metrics() {
+        let mut data = vec![];
+        data.extend_from_slice(&0x0003_0000u32.to_be_bytes()); // format 3.0
+        data.extend_from_slice(&(-819_200i32).to_be_bytes()); // italic_angle, -12.5
+        data.extend_from_slice(&(-75i16).to_be_bytes()); // underline_position
+        data.extend_from_slice(&50i16.to_be_bytes()); // underline_thickness
+        data.extend_from_slice(&1u32.to_be_bytes()); // is_fixed_pitch
+        data.extend_from_slice(&[0; 16]); // memory usage
+
+        let table = PostTable::from_data(&data).unwrap();
+        assert!((table.italic_angle + 12.5).abs() < f32::EPSILON);
+        assert_eq!(table.underline_position, -75);
+        assert_eq!(table.underline_thickness, 50);
+        assert!(table.is_monospaced);
+        assert!(table.glyph_names.is_empty());
+    }
+}
@@ -16,10 +16,26 @@ macro_rules! read_type {
 pub struct BinaryReader<'a> {
     data: &'a [u8],
     pos: usize,
+    strict: bool,
 }
 impl BinaryReader<'_> {
     pub fn new(data: &'_ [u8]) -> BinaryReader<'_> {
-        BinaryReader { data, pos: 0 }
+        BinaryReader {
+            data,
+            pos: 0,
+            strict: false,
+        }
+    }
+
+    /// Returns true if the reader is in strict mode  
+    /// In strict mode, parsers return errors for unsupported or invalid data instead of skipping it
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Enables or disables strict mode - see [`BinaryReader::is_strict`]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
     }
 
     /// Returns the current position of the reader
@@ -45,6 +61,25 @@ impl BinaryReader<'_> {
         }
     }
 
+    /// Checks that the data starting at `start` was read to exactly its declared `length`  
+    /// If `exact` is false, only reading beyond the declared length is a mismatch
+    ///
+    /// In strict mode a mismatch is an error - otherwise it is only reported with the `debug-parser` feature
+    pub fn check_length(&self, start: usize, length: usize, exact: bool) -> ParseResult<()> {
+        let consumed = self.pos.saturating_sub(start);
+        if consumed == length || (!exact && consumed < length) {
+            return Ok(());
+        }
+
+        let message = format!("Declared length of {length} bytes, but {consumed} bytes were read");
+        if self.strict {
+            return Err(self.err(&message));
+        }
+
+        debug_msg!("  Warning: {message}");
+        Ok(())
+    }
+
     /// Set the current position of the reader
     pub fn advance_to(&mut self, offset: usize) -> ParseResult<()> {
         if offset > self.data.len() {
@@ -154,16 +189,49 @@ impl BinaryReader<'_> {
         read_type!(self, i16)
     }
 
+    /// Read an unsigned big-endian integer that is `N` bytes wide, for widths without a native type
+    fn read_uint<const N: usize>(&mut self, desc: &'static str) -> ParseResult<u64> {
+        self.read_array::<N>()
+            .map(|data| {
+                data.iter()
+                    .fold(0, |acc, byte| (acc << 8) | u64::from(*byte))
+            })
+            .map_err(|err| err.with_desc(desc))
+    }
+
+    /// Read a signed big-endian integer that is `N` bytes wide, for widths without a native type
+    fn read_int<const N: usize>(&mut self, desc: &'static str) -> ParseResult<i64> {
+        let shift = 64 - 8 * N;
+        self.read_uint::<N>(desc)
+            .map(|value| ((value << shift) as i64) >> shift)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
     pub fn read_u24(&mut self) -> ParseResult<u32> {
-        self.read(3)
-            .map(|data| (u32::from(data[0]) << 16) | (u32::from(data[1]) << 8) | u32::from(data[2]))
-            .map_err(|err| err.with_desc("u24"))
+        self.read_uint::<3>("u24").map(|value| value as u32)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn read_i24(&mut self) -> ParseResult<i32> {
+        self.read_int::<3>("i24").map(|value| value as i32)
     }
 
     pub fn read_u32(&mut self) -> ParseResult<u32> {
         read_type!(self, u32)
     }
 
+    pub fn read_u48(&mut self) -> ParseResult<u64> {
+        self.read_uint::<6>("u48")
+    }
+
+    pub fn read_u64(&mut self) -> ParseResult<u64> {
+        read_type!(self, u64)
+    }
+
+    pub fn read_i64(&mut self) -> ParseResult<i64> {
+        read_type!(self, i64)
+    }
+
     /// From the TTF docs; `16.16-bit signed fixed-point number`
     /// This is a 32-bit value, where the first 16 bits are the integer part, and the last 16 bits are the fractional part.
     pub fn read_fixed32(&mut self) -> ParseResult<(i16, u16)> {
@@ -177,9 +245,45 @@ impl BinaryReader<'_> {
         Ok(f64::from(value) / f64::from(1 << 14))
     }
 
+    /// Read a string of the given size from the current position  
+    /// Invalid UTF-8 sequences are replaced with `U+FFFD`, or return an error in strict mode
     pub fn read_string(&mut self, size: usize) -> ParseResult<String> {
-        let data = self.read(size)?;
-        unsafe { Ok(String::from_utf8_unchecked(data.to_vec())) }
+        let (pos, strict) = (self.pos, self.strict);
+        let data = self.read(size).map_err(|err| err.with_desc("string"))?;
+        if strict {
+            String::from_utf8(data.to_vec()).map_err(|_| ParseError::Parse {
+                pos,
+                message: "Invalid UTF-8 in string".to_string(),
+            })
+        } else {
+            Ok(String::from_utf8_lossy(data).into_owned())
+        }
+    }
+
+    /// Read a string prefixed by its length as a `u8`, as used by the post table  
+    /// Decoded the same way as [`BinaryReader::read_string`]
+    pub fn read_pascal_string(&mut self) -> ParseResult<String> {
+        let len = self
+            .read_u8()
+            .map_err(|err| err.with_desc("string length"))?;
+        self.read_string(len as usize)
+    }
+
+    /// Read a null-terminated string from the current position, consuming the terminator  
+    /// Decoded the same way as [`BinaryReader::read_string`]
+    pub fn read_cstring(&mut self) -> ParseResult<String> {
+        let remaining = &self.data[self.pos..];
+        let Some(len) = remaining.iter().position(|byte| *byte == 0) else {
+            return Err(ParseError::UnexpectedEof {
+                pos: self.pos,
+                size: remaining.len() + 1,
+                desc: Some("null-terminated string"),
+            });
+        };
+
+        let string = self.read_string(len)?;
+        self.skip_u8()?;
+        Ok(string)
     }
 }
 
@@ -243,6 +347,25 @@ mod test {
         assert!(reader.read_u24().is_err());
     }
 
+    #[test]
+    fn test_read_i24() {
+        let data = [0xFF, 0xFF, 0xFE, 0x01, 0x02, 0x03, 0x04];
+        let mut reader = BinaryReader::new(&data);
+
+        assert_eq!(reader.read_i24().unwrap(), -2);
+        assert_eq!(reader.read_i24().unwrap(), 0x01_02_03);
+        assert!(reader.read_i24().is_err());
+    }
+
+    #[test]
+    fn test_read_u48() {
+        let data = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+        let mut reader = BinaryReader::new(&data);
+
+        assert_eq!(reader.read_u48().unwrap(), 0x01_02_03_04_05_06);
+        assert!(reader.read_u48().is_err());
+    }
+
     #[test]
     fn test_read_u32() {
         let data = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
@@ -252,6 +375,15 @@ mod test {
         assert!(reader.read_u32().is_err());
     }
 
+    #[test]
+    fn test_read_i64() {
+        let data = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x01];
+        let mut reader = BinaryReader::new(&data);
+
+        assert_eq!(reader.read_i64().unwrap(), -2);
+        assert!(reader.read_i64().is_err());
+    }
+
     #[test]
     fn test_read_fixed32() {
         let data = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
@@ -281,6 +413,31 @@ mod test {
         assert_eq!(reader.read_string(5).unwrap(), "Hello");
         assert_eq!(reader.read_string(7).unwrap(), ", World");
         assert!(reader.read_string(2).is_err());
+
+        let data = [b'a', 0xFF, b'b'];
+        let mut reader = BinaryReader::new(&data);
+        assert_eq!(reader.read_string(3).unwrap(), "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn test_read_pascal_string() {
+        let data = b"\x05Hello\x00\x03ab";
+        let mut reader = BinaryReader::new(data);
+
+        assert_eq!(reader.read_pascal_string().unwrap(), "Hello");
+        assert_eq!(reader.read_pascal_string().unwrap(), "");
+        assert!(reader.read_pascal_string().is_err());
+    }
+
+    #[test]
+    fn test_read_cstring() {
+        let data = b"Hello\x00\x00World";
+        let mut reader = BinaryReader::new(data);
+
+        assert_eq!(reader.read_cstring().unwrap(), "Hello");
+        assert_eq!(reader.read_cstring().unwrap(), "");
+        assert!(reader.read_cstring().is_err());
+        assert_eq!(reader.pos(), 7);
     }
 
     #[test]
@@ -1,5 +1,5 @@
 #![allow(clippy::similar_names)]
-use std::cmp::Ordering;
+use std::{borrow::Cow, cmp::Ordering};
 
 pub trait PartialSvgExt {
     /// Returns the outline of this glyph a set of svg objects, not wrapped in an svg container
@@ -8,47 +8,145 @@ pub trait PartialSvgExt {
 
 /// Implements methods for converting a glyph to an SVG representation
 pub trait SvgExt {
-    /// Returns the outline of this glyph as an SVG document
+    /// Returns the outline of this glyph as an SVG document, rendered using the specified options
     #[must_use]
-    fn to_svg(&self) -> String;
+    fn to_svg_with_options(&self, options: &SvgOptions) -> String;
+}
 
-    /// Returns the gzip compressed SVGZ data of this glyph
-    ///
-    /// # Errors
-    /// Returns an error if the data cannot be compressed
-    #[cfg(feature = "extended-svg")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "extended-svg")))]
-    fn to_svgz(&self) -> std::io::Result<Vec<u8>> {
-        use flate2::write::GzEncoder;
-        use std::io::Write;
-
-        let mut buffer = Vec::new();
-        let outline = self.to_svg();
-        let mut encoder = GzEncoder::new(&mut buffer, flate2::Compression::best());
-        encoder.write_all(outline.as_bytes())?;
-        encoder.finish()?;
-
-        Ok(buffer)
+/// Returns the gzip compressed SVGZ data of an SVG document
+#[cfg(feature = "extended-svg")]
+pub fn compress_svg(svg: &str) -> std::io::Result<Vec<u8>> {
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+
+    let mut buffer = Vec::new();
+    let mut encoder = GzEncoder::new(&mut buffer, flate2::Compression::best());
+    encoder.write_all(svg.as_bytes())?;
+    encoder.finish()?;
+
+    Ok(buffer)
+}
+
+/// Generates a `data:` link containing an SVG document
+#[cfg(feature = "extended-svg")]
+pub fn svg_dataimage_url(svg: &str) -> std::io::Result<String> {
+    use base64::{engine::general_purpose::STANDARD, write::EncoderStringWriter};
+    use std::io::Write;
+
+    let mut encoder = EncoderStringWriter::new(&STANDARD);
+    encoder.write_all(svg.as_bytes())?;
+
+    let data = encoder.into_inner();
+    let url = format!("data:image/svg+xml;base64,{data}");
+    Ok(url)
+}
+
+/// Renders an SVG document into a `size`x`size` PNG image, using `resvg`
+#[cfg(feature = "resvg")]
+pub fn render_svg_png(svg: &str, size: u32) -> std::io::Result<Vec<u8>> {
+    use resvg::{tiny_skia, usvg};
+    use std::io::{Error, ErrorKind};
+
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default())
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+    let mut pixmap = tiny_skia::Pixmap::new(size, size)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "Invalid image size"))?;
+
+    //
+    // Fit the svg into the image, preserving its aspect ratio
+    #[allow(clippy::cast_precision_loss)]
+    let size = size as f32;
+    let (width, height) = (tree.size().width(), tree.size().height());
+    let scale = size / width.max(height);
+    let transform = tiny_skia::Transform::from_scale(scale, scale)
+        .post_translate((size - width * scale) / 2.0, (size - height * scale) / 2.0);
+
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    pixmap.encode_png().map_err(Error::other)
+}
+
+/// Options controlling how glyph outlines are rendered as SVG
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SvgOptions {
+    /// If provided, the width of the SVG - the height is calculated from the aspect ratio of the outline  
+    /// Otherwise the SVG is sized to the outline, in font units
+    pub scale_to: Option<f32>,
+
+    /// If provided, the height of the SVG - the width is calculated from the aspect ratio of the outline  
+    /// Takes priority over `scale_to`
+    pub scale_to_height: Option<f32>,
+
+    /// If provided, the horizontal margin added around the outline, in font units  
+    /// The vertical margin is calculated from the aspect ratio of the outline
+    pub margin: Option<f32>,
+
+    /// The rule used to fill the outline
+    pub fill_rule: FillRule,
+
+    /// If provided, the outline is drawn as an unfilled stroke instead of a filled shape  
+    /// Useful for visually debugging parsed outlines
+    pub stroke: Option<SvgStroke>,
+}
+impl SvgOptions {
+    /// The default options - a 75 pixel wide SVG, with a 50 unit margin, using the nonzero fill rule
+    pub const DEFAULT: Self = Self {
+        scale_to: Some(75.0),
+        scale_to_height: None,
+        margin: Some(50.0),
+        fill_rule: FillRule::NonZero,
+        stroke: None,
+    };
+}
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self::DEFAULT
     }
+}
 
-    /// Generates a `data:` link containing the outline svg data for this glyph  
-    ///
-    /// # Errors
-    /// Returns an error if the data cannot be encoded properly
-    #[cfg(feature = "extended-svg")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "extended-svg")))]
-    fn to_svg_dataimage_url(&self) -> std::io::Result<String> {
-        use base64::{engine::general_purpose::STANDARD, write::EncoderStringWriter};
-        use std::io::Write;
-
-        let buffer = self.to_svg().into_bytes();
-
-        let mut encoder = EncoderStringWriter::new(&STANDARD);
-        encoder.write_all(&buffer)?;
-
-        let data = encoder.into_inner();
-        let url = format!("data:image/svg+xml;base64,{data}",);
-        Ok(url)
+/// The stroke used to draw an outline when debugging
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SvgStroke {
+    /// Width of the stroke, in font units  
+    /// Larger glyphs will need a wider stroke to remain visible
+    pub width: f32,
+
+    /// Color of the stroke, as any valid SVG color, such as `red` or `#FF0000`
+    pub color: &'static str,
+}
+impl SvgStroke {
+    /// A 5 unit wide red stroke
+    pub const DEFAULT: Self = Self {
+        width: 5.0,
+        color: "red",
+    };
+}
+impl Default for SvgStroke {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The rule used to decide which parts of an outline are filled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FillRule {
+    /// Areas enclosed by contours winding in the same direction are filled  
+    /// This is how TrueType outlines are defined
+    #[default]
+    NonZero,
+
+    /// Areas enclosed by an odd number of contours are filled  
+    /// Overlapping contours will cut holes into each other
+    EvenOdd,
+}
+impl FillRule {
+    /// Returns the SVG name of the fill rule
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NonZero => "nonzero",
+            Self::EvenOdd => "evenodd",
+        }
     }
 }
 
@@ -64,9 +162,19 @@ pub struct SvgProperties {
     /// A vertical size will be calculated based on the aspect ratio of the viewbox
     pub scale_to: Option<f32>,
 
+    /// If provided, represents the vertical view size, and overrides `scale_to`  
+    /// A horizontal size will be calculated based on the aspect ratio of the viewbox
+    pub scale_to_height: Option<f32>,
+
     /// If provided, represents the horizontal margin to add to the viewbox  
     /// A vertical margin will be calculated based on the aspect ratio of the viewbox
     pub margin: Option<f32>,
+
+    /// The rule used to fill the outline
+    pub fill_rule: FillRule,
+
+    /// If provided, the outline is stroked instead of filled
+    pub stroke: Option<SvgStroke>,
 }
 
 pub enum SvgPathComponent {
@@ -219,6 +327,63 @@ impl SvgPathComponent {
         }
     }
 
+    /// Approximates a cubic bezier as a chain of quadratic beziers, for outlines (such as CFF) that use cubic curves  
+    /// The curve is split in half until each piece is within `tolerance` units of its quadratic approximation
+    ///
+    /// Points are `(x, y)`; `start` is the current point, and is not included in the output
+    #[allow(dead_code)] // Not yet used - no parsed outline format produces cubic curves
+    pub fn cubic_to_quadratic(
+        start: (i16, i16),
+        ctrl1: (i16, i16),
+        ctrl2: (i16, i16),
+        end: (i16, i16),
+        tolerance: f32,
+    ) -> Vec<Self> {
+        let to_f32 = |(x, y): (i16, i16)| (f32::from(x), f32::from(y));
+        let curve = [to_f32(start), to_f32(ctrl1), to_f32(ctrl2), to_f32(end)];
+
+        let mut path = vec![];
+        Self::subdivide_cubic(curve, tolerance.max(0.0), 0, &mut path);
+        path
+    }
+
+    /// Recursive step of [`Self::cubic_to_quadratic`]
+    #[allow(dead_code)]
+    #[allow(clippy::cast_possible_truncation)]
+    fn subdivide_cubic(curve: [(f32, f32); 4], tolerance: f32, depth: u8, path: &mut Vec<Self>) {
+        const MAX_DEPTH: u8 = 8;
+        let [p0, p1, p2, p3] = curve;
+
+        //
+        // The best single quadratic shares the endpoints, with its control point at (3(p1 + p2) - p0 - p3) / 4
+        // Its maximum distance from the cubic is sqrt(3)/36 * |p3 - 3p2 + 3p1 - p0|
+        let dx = p3.0 - 3.0 * p2.0 + 3.0 * p1.0 - p0.0;
+        let dy = p3.1 - 3.0 * p2.1 + 3.0 * p1.1 - p0.1;
+        let error = 3f32.sqrt() / 36.0 * dx.hypot(dy);
+
+        if error <= tolerance || depth >= MAX_DEPTH {
+            let qx = (3.0 * (p1.0 + p2.0) - p0.0 - p3.0) / 4.0;
+            let qy = (3.0 * (p1.1 + p2.1) - p0.1 - p3.1) / 4.0;
+            path.push(Self::QuadraticBezier(
+                qx.round() as i16,
+                qy.round() as i16,
+                p3.0.round() as i16,
+                p3.1.round() as i16,
+            ));
+            return;
+        }
+
+        //
+        // Split at t=0.5 (de Casteljau)
+        let mid = |a: (f32, f32), b: (f32, f32)| (f32::midpoint(a.0, b.0), f32::midpoint(a.1, b.1));
+        let (p01, p12, p23) = (mid(p0, p1), mid(p1, p2), mid(p2, p3));
+        let (p012, p123) = (mid(p01, p12), mid(p12, p23));
+        let center = mid(p012, p123);
+
+        Self::subdivide_cubic([p0, p01, p012, center], tolerance, depth + 1, path);
+        Self::subdivide_cubic([center, p123, p23, p3], tolerance, depth + 1, path);
+    }
+
     pub fn line_components(&self) -> Option<(i16, i16)> {
         match self {
             Self::MoveTo(x, y) | Self::LineTo(x, y) => Some((*x, *y)),
@@ -245,6 +410,26 @@ impl SvgPathComponent {
     }
 }
 
+/// Escapes text for use inside an SVG element or attribute
+pub fn escape_xml(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '\'', '"']) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len() + 8);
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '\'' => out.push_str("&apos;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
 /// Wrap a set of SVG components in an SVG container
 pub fn wrap_svg_component(properties: &SvgProperties, component: &str) -> String {
     let (width, height) = properties.viewbox_size;
@@ -262,13 +447,49 @@ pub fn wrap_svg_component(properties: &SvgProperties, component: &str) -> String
     let (width, height) = (width + 2.0 * x_margin, height + 2.0 * y_margin);
 
     //
-    // Calculate new height
-    let vwidth = properties.scale_to.unwrap_or(width);
-    let vheight = vwidth / aspect_ratio;
+    // Calculate the rendered size
+    let (vwidth, vheight) = if let Some(vheight) = properties.scale_to_height {
+        (vheight * aspect_ratio, vheight)
+    } else {
+        let vwidth = properties.scale_to.unwrap_or(width);
+        (vwidth, vwidth / aspect_ratio)
+    };
 
     //
     // Put the pieces together
     let vsize = format!("width='{vwidth}' height='{vheight}'");
-    let viewbox = format!("viewBox='{xmin} {ymin} {width} {height}'",);
-    format!("<svg xmlns='http://www.w3.org/2000/svg' style='background-color:#FFF' {vsize} {viewbox}>{component}</svg>")
+    let viewbox = format!("viewBox='{xmin} {ymin} {width} {height}'");
+    let paint = match properties.stroke {
+        Some(stroke) => format!(
+            "fill='none' stroke='{}' stroke-width='{}'",
+            stroke.color, stroke.width
+        ),
+        None => format!("fill-rule='{}'", properties.fill_rule.as_str()),
+    };
+    format!("<svg xmlns='http://www.w3.org/2000/svg' style='background-color:#FFF' {vsize} {viewbox} {paint}>{component}</svg>")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cubic_to_quadratic() {
+        // A cubic that is exactly a quadratic is not subdivided
+        let path = SvgPathComponent::cubic_to_quadratic((0, 0), (20, 40), (40, 40), (60, 0), 0.5);
+        assert_eq!(path.len(), 1);
+        assert_eq!(path[0].components(), ('Q', vec![30, 60, 60, 0]));
+
+        // An S-curve needs several pieces, and ends on the cubic's endpoint
+        let coarse =
+            SvgPathComponent::cubic_to_quadratic((0, 0), (100, 0), (0, 100), (100, 100), 10.0);
+        let fine =
+            SvgPathComponent::cubic_to_quadratic((0, 0), (100, 0), (0, 100), (100, 100), 0.5);
+        assert!(coarse.len() > 1);
+        assert!(fine.len() > coarse.len());
+        for path in [coarse, fine] {
+            let (cmd, args) = path.last().unwrap().components();
+            assert_eq!((cmd, &args[2..]), ('Q', &[100, 100][..]));
+        }
+    }
 }
@@ -0,0 +1,16 @@
+//! Smoke test for the `wasm32-unknown-unknown` target  
+//! Run with `wasm-bindgen-test-runner` as the cargo runner
+#![cfg(target_arch = "wasm32")]
+use font_map_core::font::Font;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const FONT: &[u8] = include_bytes!("../../examples/slick.ttf");
+
+#[wasm_bindgen_test]
+fn test_parse_and_render() {
+    let font = Font::new(FONT).unwrap();
+    assert!(!font.glyphs().is_empty());
+
+    let glyph = font.glyph_named("bullet").unwrap();
+    assert!(glyph.svg_preview().starts_with("<svg"));
+}
@@ -14,8 +14,10 @@ fn main() -> ParseResult<()> {
 
     font.glyphs().iter().for_each(|glyph| {
         let name = glyph.name();
-        let codepoint = glyph.codepoint();
-        println!("- `{name}` has codepoint {codepoint:04x}");
+        match glyph.codepoint() {
+            Some(codepoint) => println!("- `{name}` has codepoint {codepoint:04x}"),
+            None => println!("- `{name}` has no codepoint"),
+        }
 
         //
         // You can also export the glyph as an image
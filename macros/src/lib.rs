@@ -1,11 +1,14 @@
-use font_map_core::{codegen::FontDesc, font::Font};
+use font_map_core::{
+    codegen::{CodegenOptions, FontDesc, IdentifierCase},
+    font::Font,
+};
 use proc_macro::TokenStream;
 use syn::{parse::Parse, parse_macro_input, Ident, Lit, LitStr};
 
 struct FontParameters {
     identifier: Ident,
     path: LitStr,
-    skip_categories: bool,
+    options: CodegenOptions,
 }
 impl Parse for FontParameters {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
@@ -13,7 +16,7 @@ impl Parse for FontParameters {
         input.parse::<syn::Token![,]>()?;
         let path = input.parse()?;
 
-        let mut skip_categories = false;
+        let mut options = CodegenOptions::default();
 
         while input.parse::<syn::Token![,]>().is_ok() {
             let name = input.parse::<Ident>()?;
@@ -22,7 +25,7 @@ impl Parse for FontParameters {
 
             match name {
                 n if n == "skip_categories" => match value {
-                    Lit::Bool(b) => skip_categories = b.value,
+                    Lit::Bool(b) => options.skip_categories = b.value,
                     _ => {
                         return Err(syn::Error::new_spanned(
                             value,
@@ -31,10 +34,74 @@ impl Parse for FontParameters {
                     }
                 },
 
+                n if n == "skip_previews" => match value {
+                    Lit::Bool(b) => options.skip_previews = b.value,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "Expected a boolean value for `skip_previews`",
+                        ))
+                    }
+                },
+
+                n if n == "category_flags" => match value {
+                    Lit::Bool(b) => options.category_flags = b.value,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "Expected a boolean value for `category_flags`",
+                        ))
+                    }
+                },
+
+                n if n == "normalize_symbol_codepoints" => match value {
+                    Lit::Bool(b) => options.normalize_symbol_codepoints = b.value,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "Expected a boolean value for `normalize_symbol_codepoints`",
+                        ))
+                    }
+                },
+
+                n if n == "skip_non_printing" => match value {
+                    Lit::Bool(b) => options.skip_non_printing = b.value,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "Expected a boolean value for `skip_non_printing`",
+                        ))
+                    }
+                },
+
+                n if n == "iced_helpers" => match value {
+                    Lit::Bool(b) => options.iced_helpers = b.value,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "Expected a boolean value for `iced_helpers`",
+                        ))
+                    }
+                },
+
+                n if n == "identifier_case" => {
+                    options.identifier_case = match &value {
+                        Lit::Str(s) if s.value() == "pascal" => IdentifierCase::Pascal,
+                        Lit::Str(s) if s.value() == "snake" => IdentifierCase::Snake,
+                        Lit::Str(s) if s.value() == "screaming" => IdentifierCase::ScreamingSnake,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                value,
+                                "Expected `\"pascal\"`, `\"snake\"` or `\"screaming\"` for `identifier_case`",
+                            ))
+                        }
+                    }
+                }
+
                 _ => {
                     return Err(syn::Error::new_spanned(
                         name,
-                        "Unknown parameter, expected `skip_categories`",
+                        "Unknown parameter, expected `skip_categories`, `skip_previews`, `category_flags`, `normalize_symbol_codepoints`, `skip_non_printing`, `iced_helpers` or `identifier_case`",
                     ))
                 }
             }
@@ -43,7 +110,7 @@ impl Parse for FontParameters {
         Ok(Self {
             identifier,
             path,
-            skip_categories,
+            options,
         })
     }
 }
@@ -62,6 +129,6 @@ pub fn font(input: TokenStream) -> TokenStream {
         std::fs::read(&path).unwrap_or_else(|_| panic!("Failed to read font at `{path}`"));
     let font = Font::new(&font_bytes).unwrap_or_else(|_| panic!("Invalid font file: `{path}`"));
 
-    let generator = FontDesc::from_font(&identifier, &font, input.skip_categories);
+    let generator = FontDesc::with_options(&identifier, &font, &input.options);
     generator.codegen(None).into()
 }
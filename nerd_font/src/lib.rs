@@ -39,6 +39,7 @@
 //! - Unicode codepoint: e.g. `Dev::Android as u32`
 //! - Postfix name: e.g. `Dev::Android.name()`
 //! - Glyph preview image, visible in the documentation, and by hovering over the glyphs in your IDE!
+//! - SVG preview, for rendering without loading the font: e.g. `Dev::Android.svg()`
 //!
 //! You can also get the actual char from the enum, with `char::from(Dev::Android)`, or `Dev::Android.to_string()`
 //!
@@ -60,12 +61,58 @@
 //!
 //! You will additionally need to load the font, by calling `.font(NerdFont::FONT_BYTES)` on your `iced::Application`.
 //!
+//! If you use `ratatui` there is also a helper for terminal UIs:  
+//! **NOTE: ** you will need to activate the `ratatui` crate-level feature to use these!
+//!
+//! - [`RatatuiExt`] converts glyphs into a `ratatui::text::Span` containing the glyph's char
+//! - `NerdFont` also implements `Into<ratatui::text::Span>`
+//!
+//! ```ignore
+//! use nerd_font::{RatatuiExt, categories::Dev};
+//!
+//! // A span containing the selected glyph, optionally styled
+//! let span = Dev::Android.into_span();
+//! let styled_span = Dev::Android.into_styled_span(ratatui::style::Color::Green);
+//! ```
+//!
+//! Terminals cannot load fonts, so the terminal itself needs to be using a font containing the glyphs.
+//!
+//! -----
+//!
+//! If you use `bevy` there are some QOL features built-in:  
+//! **NOTE: ** you will need to activate the `bevy` crate-level feature to use these!
+//!
+//! - [`BevyExt`] provides the helper functions for using the font in bevy UIs
+//!
+//! ```ignore
+//! use bevy::prelude::*;
+//! use nerd_font::{BevyExt, NerdFont, categories::Dev};
+//!
+//! fn setup(mut commands: Commands, mut fonts: ResMut<Assets<Font>>) {
+//!     // Register the icon font as a bevy asset
+//!     let font = fonts.add(NerdFont::bevy_font());
+//!
+//!     // Spawn a UI text node containing the selected glyph, with a font size of 24
+//!     commands.spawn(Dev::Android.into_bevy_text(font, 24.0));
+//! }
+//! ```
+//!
+//! -----
+//!
 //! ## Crate Features
 //!
 //! #### `iced`
 //! Default: Off  
 //! Provides some QOL features for using the font in iced, including a font definition, and conversion to an iced Text widget.
 //!
+//! #### `ratatui`
+//! Default: Off  
+//! Provides conversion of glyphs into ratatui text spans, for use in terminal UIs.
+//!
+//! #### `bevy`
+//! Default: Off  
+//! Provides some QOL features for using the font in bevy, including a font asset, and conversion to bevy UI text components.
+//!
 #![warn(missing_docs)]
 #![warn(clippy::pedantic)]
 #![allow(clippy::doc_comment_double_space_linebreaks)]
@@ -140,6 +187,109 @@ impl<Message> From<NerdFont> for iced::Element<'_, Message> {
     }
 }
 
+/// Extension trait for using these icons from within ratatui
+///
+/// - `NerdFont` also implements `Into<ratatui::text::Span>`
+///
+/// ```ignore
+/// use nerd_font::{RatatuiExt, categories::Dev};
+///
+/// // A span containing the selected glyph, optionally styled
+/// let span = Dev::Android.into_span();
+/// let styled_span = Dev::Android.into_styled_span(ratatui::style::Color::Green);
+/// ```
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+pub trait RatatuiExt {
+    /// Converts this enum into a ratatui Span containing the glyph
+    #[must_use]
+    fn into_span<'a>(self) -> ratatui::text::Span<'a>;
+
+    /// Converts this enum into a ratatui Span containing the glyph  
+    /// Applies the given style to the new span
+    #[must_use]
+    fn into_styled_span<'a>(
+        self,
+        style: impl Into<ratatui::style::Style>,
+    ) -> ratatui::text::Span<'a>;
+}
+
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+impl<S: Into<NerdFont>> RatatuiExt for S {
+    fn into_span<'a>(self) -> ratatui::text::Span<'a> {
+        let glyph = char::from(Into::<NerdFont>::into(self));
+        ratatui::text::Span::raw(glyph.to_string())
+    }
+
+    fn into_styled_span<'a>(
+        self,
+        style: impl Into<ratatui::style::Style>,
+    ) -> ratatui::text::Span<'a> {
+        self.into_span().style(style)
+    }
+}
+
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+impl From<NerdFont> for ratatui::text::Span<'_> {
+    fn from(value: NerdFont) -> Self {
+        value.into_span()
+    }
+}
+
+/// Extension trait for using these icons from within bevy
+///
+/// ```ignore
+/// use bevy::prelude::*;
+/// use nerd_font::{BevyExt, NerdFont, categories::Dev};
+///
+/// fn setup(mut commands: Commands, mut fonts: ResMut<Assets<Font>>) {
+///     // Register the icon font as a bevy asset
+///     let font = fonts.add(NerdFont::bevy_font());
+///
+///     // Spawn a UI text node containing the selected glyph, with a font size of 24
+///     commands.spawn(Dev::Android.into_bevy_text(font, 24.0));
+/// }
+/// ```
+#[cfg(feature = "bevy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy")))]
+pub trait BevyExt {
+    /// Returns this font as a bevy font asset  
+    /// Add it to `Assets<Font>` to get the handle used by [`BevyExt::into_bevy_text`]
+    #[must_use]
+    fn bevy_font() -> bevy::text::Font;
+
+    /// Converts this enum into the components for a bevy UI text node  
+    /// Uses the given font handle and font-size
+    #[must_use]
+    fn into_bevy_text(
+        self,
+        font: bevy::asset::Handle<bevy::text::Font>,
+        font_size: f32,
+    ) -> (bevy::ui::widget::Text, bevy::text::TextFont);
+}
+
+#[cfg(feature = "bevy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy")))]
+impl<S: Into<NerdFont>> BevyExt for S {
+    fn bevy_font() -> bevy::text::Font {
+        bevy::text::Font::try_from_bytes(NerdFont::FONT_BYTES.to_vec())
+            .expect("Bundled font was invalid!")
+    }
+
+    fn into_bevy_text(
+        self,
+        font: bevy::asset::Handle<bevy::text::Font>,
+        font_size: f32,
+    ) -> (bevy::ui::widget::Text, bevy::text::TextFont) {
+        let glyph = char::from(Into::<NerdFont>::into(self));
+        let text = bevy::ui::widget::Text::new(glyph.to_string());
+        let text_font = bevy::text::TextFont::from_font_size(font_size).with_font(font);
+        (text, text_font)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -151,5 +301,20 @@ mod test {
         assert!(!font.glyphs().is_empty());
         assert!(!NerdFont::FONT_BYTES.is_empty());
         let _ = categories::Dev::Ansible;
+        let glyph = NerdFont::from(categories::Dev::Ansible);
+        assert!(glyph.svg().starts_with("<svg"));
+
+        let parsed = font.glyph(u32::from(glyph)).unwrap();
+        assert_eq!(glyph.advance_width(), parsed.advance_width());
+        assert_eq!(glyph.left_side_bearing(), parsed.left_side_bearing());
+    }
+
+    #[test]
+    fn test_all() {
+        static ALL: [NerdFont; NerdFont::TOTAL_GLYPHS] = NerdFont::ALL;
+        assert!(ALL.len() > categories::Dev::TOTAL_GLYPHS);
+
+        let ansible = u32::from(categories::Dev::Ansible);
+        assert!(ALL.iter().any(|glyph| u32::from(glyph) == ansible));
     }
 }
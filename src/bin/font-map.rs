@@ -0,0 +1,91 @@
+//!
+//! Command-line utility for inspecting font files
+//!
+//! ```text
+//! font-map info <font>          Print a summary of the font, and its stored strings
+//! font-map list <font>          Print the name and codepoint of every glyph
+//! font-map svg <font> <glyph>   Print the SVG outline of the named glyph
+//! font-map gen <font>           Print the generated enum for the font
+//!     --name <name>             Name of the generated enum - defaults to `Icon`
+//! ```
+//!
+//! Requires the `cli` feature
+//!
+use font_map::font::Font;
+use std::error::Error;
+
+const USAGE: &str = "Usage:
+    font-map info <font>          Print a summary of the font, and its stored strings
+    font-map list <font>          Print the name and codepoint of every glyph
+    font-map svg <font> <glyph>   Print the SVG outline of the named glyph
+    font-map gen <font>           Print the generated enum for the font
+        --name <name>             Name of the generated enum - defaults to `Icon`";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["info", path] => info(path),
+        ["list", path] => list(path),
+        ["svg", path, name] => svg(path, name),
+        ["gen", path] => gen(path, "Icon"),
+        ["gen", "--name", name, path] | ["gen", path, "--name", name] => gen(path, name),
+
+        _ => {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn info(path: &str) -> Result<(), Box<dyn Error>> {
+    let font = Font::from_file(path)?;
+    println!("{font}");
+
+    for (kind, value) in font.all_strings() {
+        println!("  {kind:?}: {value}");
+    }
+
+    Ok(())
+}
+
+fn list(path: &str) -> Result<(), Box<dyn Error>> {
+    let font = Font::from_file(path)?;
+    for glyph in font.glyphs() {
+        let codepoint = glyph.codepoint_hex().unwrap_or_else(|| "-".to_string());
+        println!("{codepoint}\t{}", glyph.name());
+    }
+
+    Ok(())
+}
+
+fn svg(path: &str, name: &str) -> Result<(), Box<dyn Error>> {
+    let font = Font::from_file(path)?;
+    let glyph = font
+        .glyph_named(name)
+        .ok_or_else(|| format!("No glyph named `{name}` in `{path}`"))?;
+
+    println!("{}", glyph.svg_preview());
+    Ok(())
+}
+
+fn gen(path: &str, name: &str) -> Result<(), Box<dyn Error>> {
+    syn::parse_str::<syn::Ident>(name)
+        .map_err(|e| format!("`{name}` is not a valid enum name: {e}"))?;
+
+    let font = Font::from_file(path)?;
+    let tokens = font.to_enum_tokens(name);
+
+    //
+    // Format the generated code before printing it
+    let file: syn::File = syn::parse2(tokens)?;
+    print!("{}", prettyplease::unparse(&file));
+
+    Ok(())
+}
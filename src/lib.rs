@@ -43,10 +43,31 @@
 //! # }
 //! ```
 //!
+//! -----
+//!
+//! The crate also ships a small `font-map` binary for inspecting fonts from the command line  
+//! Install it with `cargo install font-map --features cli`:
+//!
+//! ```text
+//! font-map info <font>          Print a summary of the font, and its stored strings
+//! font-map list <font>          Print the name and codepoint of every glyph
+//! font-map svg <font> <glyph>   Print the SVG outline of the named glyph
+//! font-map gen <font>           Print the generated enum for the font
+//!     --name <name>             Name of the generated enum - defaults to `Icon`
+//! ```
+//!
 //! ## Features
 //! - `macros` - Enables the `font!` macro for code generation
 //! - `codegen` - Enables the `FontCodegenExt` trait for runtime code generation
 //! - `extended-svg` - Enables compressed and base64 encoded SVG data in the generated code (Needed for image previews)
+//! - `std-fs` - Enables loading fonts from the filesystem with `Font::from_file` - disable it for targets like `wasm32-unknown-unknown`
+//! - `image` - Enables rendering glyphs to an `image::DynamicImage` with `Glyph::to_dynamic_image`
+//! - `fontdb` - Enables registering fonts with a `fontdb::Database` using `Font::load_into_fontdb`
+//! - `resvg` - Enables rendering SVG previews to PNG images with `Glyph::render_png_via_resvg`
+//! - `serde` - Enables exporting font metadata as JSON with `Font::to_json`
+//! - `rayon` - Renders SVG previews in parallel with `Font::svg_previews_par`
+//! - `tessellate` - Enables triangulating glyph outlines into a mesh for GPU rendering with `Glyph::tessellate`
+//! - `cli` - Builds the `font-map` command-line binary
 //!
 //! ## Known Limitations
 //! This crate was made for a very specific use-case, and as such currently has a few limitations:
@@ -85,6 +106,13 @@ pub use font_map_macros::*;
 ///         path = "../examples/slick.ttf",
 ///         name = SlickFont,
 ///         skip_categories = false, /* Can be omitted - if `true`, generate one giant enum instead of a set of categories */
+///         skip_previews = false, /* Can be omitted - if `true`, omit the SVG preview images from the generated docs */
+///         category_flags = false, /* Can be omitted - if `true`, generate a bitflags-style `Category` type for the categories */
+///         sidecar_previews = false, /* Can be omitted - if `true`, write the SVG previews to separate files, to keep the generated code small */
+///         normalize_symbol_codepoints = false, /* Can be omitted - if `true`, move Microsoft Symbol glyphs from `U+F0xx` to `U+00xx` */
+///         skip_non_printing = false, /* Can be omitted - if `true`, leave `.notdef`, `.null` and `nonmarkingreturn` out of the generated enum */
+///         iced_helpers = false, /* Can be omitted - if `true`, generate `iced_font` and `into_text` methods, behind this crate's `iced` feature */
+///         identifier_case = "pascal", /* Can be omitted - `"pascal"`, `"snake"` or `"screaming"`, the case used for generated identifiers */
 ///     );
 /// }
 /// ```
@@ -95,8 +123,8 @@ pub use font_map_macros::*;
 macro_rules! build_font {
     (
         path = $path:literal,
-        name = $name:ident,
-        skip_categories = $skip_categories:literal $(,)?
+        name = $name:ident
+        $(, $option:ident = $value:literal)* $(,)?
     ) => {
         const FONT_BYTES: &[u8] = include_bytes!($path);
         println!(concat!("cargo:rerun-if-changed=", $path));
@@ -110,8 +138,17 @@ macro_rules! build_font {
         //
         // Load the font and perform code generation
         let font = font_map::font::Font::new(FONT_BYTES).expect("Bundled font was invalid!");
+        #[allow(unused_mut)]
+        let mut options = font_map::codegen::CodegenOptions::default();
+        $( $crate::__build_font_option!(options, $option = $value); )*
+
         let generator =
-            font_map::codegen::FontDesc::from_font(stringify!($name), &font, $skip_categories);
+            font_map::codegen::FontDesc::with_options(stringify!($name), &font, &options);
+        for (codepoint, kept, skipped) in generator.collisions() {
+            println!(
+                "cargo:warning=Skipped glyph `{skipped}` - codepoint U+{codepoint:04X} is already used by `{kept}`"
+            );
+        }
         let code = generator
             .codegen(Some(font_map::codegen::quote! {
                 /// The raw bytes of the font file
@@ -126,6 +163,16 @@ macro_rules! build_font {
             std::path::Path::new(&dir).join(&format!("font_generated_{}.rs", stringify!($name)));
         std::fs::write(&target, code).expect("Failed to write generated icon-enum");
 
+        //
+        // Write any sidecar preview files next to it
+        for (path, contents) in generator.sidecar_files() {
+            let path = std::path::Path::new(&dir).join(path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).expect("Failed to create preview directory");
+            }
+            std::fs::write(&path, contents).expect("Failed to write preview file");
+        }
+
         //
         // Manually run rustfmt on the generated file
         let _ = std::process::Command::new("rustfmt")
@@ -140,16 +187,23 @@ macro_rules! build_font {
             target.display()
         );
     };
+}
 
-    (
-        path = $path:literal,
-        name = $name:ident $(,)?
-    ) => {
-        $crate::build_font! {
-            path = $path,
-            name = $name,
-            skip_categories = false
-        }
+/// Applies a single option from [`build_font!`] to a `CodegenOptions`
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __build_font_option {
+    ($options:ident, identifier_case = $value:literal) => {
+        $options.identifier_case = match $value {
+            "pascal" => font_map::codegen::IdentifierCase::Pascal,
+            "snake" => font_map::codegen::IdentifierCase::Snake,
+            "screaming" => font_map::codegen::IdentifierCase::ScreamingSnake,
+            _ => panic!("Expected `\"pascal\"`, `\"snake\"` or `\"screaming\"` for `identifier_case`"),
+        };
+    };
+    ($options:ident, $option:ident = $value:literal) => {
+        $options.$option = $value;
     };
 }
 